@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate criterion;
+extern crate either;
+
+use criterion::{black_box, Criterion};
+use either::{Either, Left, Right};
+
+fn values() -> Vec<Either<u64, u32>> {
+    (0..1_000u64).map(|i| if i % 3 == 0 { Right(i as u32) } else { Left(i) }).collect()
+}
+
+fn left_ref(c: &mut Criterion) {
+    let values = values();
+    let mut group = c.benchmark_group("left_ref");
+    group.bench_function("as_ref_left", |b| {
+        b.iter(|| black_box(&values).iter().filter_map(|v| v.as_ref().left()).sum::<u64>())
+    });
+    group.bench_function("left_ref", |b| {
+        b.iter(|| black_box(&values).iter().filter_map(Either::left_ref).sum::<u64>())
+    });
+    group.finish();
+}
+
+fn right_mut(c: &mut Criterion) {
+    let mut values = values();
+    let mut group = c.benchmark_group("right_mut");
+    group.bench_function("as_mut_right", |b| {
+        b.iter(|| {
+            for r in black_box(&mut values).iter_mut().filter_map(|v| v.as_mut().right()) {
+                *r = r.wrapping_add(1);
+            }
+        })
+    });
+    group.bench_function("right_mut", |b| {
+        b.iter(|| {
+            for r in black_box(&mut values).iter_mut().filter_map(Either::right_mut) {
+                *r = r.wrapping_add(1);
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, left_ref, right_mut);
+criterion_main!(benches);
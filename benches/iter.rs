@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate criterion;
+extern crate either;
+
+use criterion::{black_box, Criterion};
+use either::{Either, Left, Right};
+use std::ops::Range;
+
+const N: u64 = 10_000;
+
+fn either_range(left: bool) -> Either<Range<u64>, Range<u64>> {
+    if black_box(left) {
+        Left(0..N)
+    } else {
+        Right(0..N)
+    }
+}
+
+fn bare_range() -> Range<u64> {
+    black_box(0..N)
+}
+
+fn sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+    group.bench_function("bare", |b| b.iter(|| bare_range().sum::<u64>()));
+    group.bench_function("either", |b| b.iter(|| either_range(true).sum::<u64>()));
+    group.finish();
+}
+
+fn fold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fold");
+    group.bench_function("bare", |b| {
+        b.iter(|| bare_range().fold(0u64, |acc, x| acc ^ x.wrapping_mul(31)))
+    });
+    group.bench_function("either", |b| {
+        b.iter(|| either_range(true).fold(0u64, |acc, x| acc ^ x.wrapping_mul(31)))
+    });
+    group.finish();
+}
+
+fn count(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count");
+    group.bench_function("bare", |b| b.iter(|| bare_range().count()));
+    group.bench_function("either", |b| b.iter(|| either_range(true).count()));
+    group.finish();
+}
+
+fn nth(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nth");
+    group.bench_function("bare", |b| b.iter(|| bare_range().nth(black_box(N as usize / 2))));
+    group.bench_function("either", |b| {
+        b.iter(|| either_range(false).nth(black_box(N as usize / 2)))
+    });
+    group.finish();
+}
+
+fn collect(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collect");
+    group.bench_function("bare", |b| b.iter(|| bare_range().collect::<Vec<_>>()));
+    group.bench_function("either", |b| b.iter(|| either_range(false).collect::<Vec<_>>()));
+    group.finish();
+}
+
+criterion_group!(benches, sum, fold, count, nth, collect);
+criterion_main!(benches);
@@ -0,0 +1,8 @@
+extern crate autocfg;
+
+fn main() {
+    let ac = autocfg::new();
+    // `factor_array` needs const generics and `array::map`.
+    ac.emit_rustc_version(1, 55);
+    autocfg::rerun_path("build.rs");
+}
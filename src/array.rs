@@ -0,0 +1,30 @@
+//! Methods for eithers of arrays that need const generics and `array::map`.
+//!
+//! This module is only compiled on Rust 1.55 or later, as detected by the build script.
+
+use {Either, Left, Right};
+
+impl<L, R, const N: usize> Either<[L; N], [R; N]> {
+    /// Distribute the choice of side over each element of an either of arrays.
+    ///
+    /// Every element of the result is on the same side as the original array.
+    ///
+    /// Requires Rust 1.55 or later.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<[u32; 3], [char; 3]> = Left([1, 2, 3]);
+    /// assert_eq!(left.factor_array(), [Left(1), Left(2), Left(3)]);
+    ///
+    /// let right: Either<[u32; 2], [char; 2]> = Right(['a', 'b']);
+    /// assert_eq!(right.factor_array(), [Right('a'), Right('b')]);
+    /// ```
+    #[allow(clippy::incompatible_msrv)]
+    pub fn factor_array(self) -> [Either<L, R>; N] {
+        match self {
+            Left(l) => l.map(Left),
+            Right(r) => r.map(Right),
+        }
+    }
+}
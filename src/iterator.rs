@@ -0,0 +1,906 @@
+//! Extension methods for iterators of `Either` values.
+
+use std::iter::Sum;
+#[cfg(any(test, feature = "use_std"))]
+use std::marker::PhantomData;
+#[cfg(any(test, feature = "use_std"))]
+use std::{mem, ptr};
+
+use Either;
+
+/// Extension trait for iterators over `Either<L, R>`.
+///
+/// This trait is implemented for every `Iterator` with `Either<L, R>` items.
+pub trait IteratorExt<L, R>: Iterator<Item = Either<L, R>> {
+    /// Count the left and right items in one pass, returning `(lefts, rights)`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left(1), Right("a"), Left(2), Left(3)];
+    /// assert_eq!(values.into_iter().count_each(), (3, 1));
+    /// ```
+    fn count_each(self) -> (usize, usize)
+        where Self: Sized
+    {
+        self.fold_partition(0, 0, |n, _| n + 1, |n, _| n + 1)
+    }
+
+    /// Fold the left items with `fl` and the right items with `fr` in one pass,
+    /// returning both accumulators as `(left, right)`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left(1), Right("a"), Left(2), Right("bc")];
+    /// let (sum, text) = values.into_iter().fold_partition(0, String::new(),
+    ///                                                     |acc, x| acc + x,
+    ///                                                     |acc, s| acc + s);
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(text, "abc");
+    /// ```
+    fn fold_partition<A, B, F, G>(self, init_l: A, init_r: B, mut fl: F, mut fr: G) -> (A, B)
+        where Self: Sized,
+              F: FnMut(A, L) -> A,
+              G: FnMut(B, R) -> B
+    {
+        self.fold((init_l, init_r), |(acc_l, acc_r), item| {
+            match item {
+                Either::Left(l) => (fl(acc_l, l), acc_r),
+                Either::Right(r) => (acc_l, fr(acc_r, r)),
+            }
+        })
+    }
+
+    /// Fold the left items with `f`, ignoring the right items.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left(1), Right("a"), Left(2)];
+    /// assert_eq!(values.into_iter().fold_lefts(0, |acc, x| acc + x), 3);
+    /// ```
+    fn fold_lefts<A, F>(self, init: A, mut f: F) -> A
+        where Self: Sized,
+              F: FnMut(A, L) -> A
+    {
+        self.fold(init, |acc, item| {
+            match item {
+                Either::Left(l) => f(acc, l),
+                Either::Right(_) => acc,
+            }
+        })
+    }
+
+    /// Fold the right items with `f`, ignoring the left items.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Right(1), Left("a"), Right(2)];
+    /// assert_eq!(values.into_iter().fold_rights(0, |acc, x| acc + x), 3);
+    /// ```
+    fn fold_rights<A, F>(self, init: A, mut f: F) -> A
+        where Self: Sized,
+              F: FnMut(A, R) -> A
+    {
+        self.fold(init, |acc, item| {
+            match item {
+                Either::Left(_) => acc,
+                Either::Right(r) => f(acc, r),
+            }
+        })
+    }
+
+    /// Sum the left items, ignoring the right items.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left(1), Right(2.5), Left(2)];
+    /// assert_eq!(values.into_iter().sum_lefts::<i32>(), 3);
+    /// ```
+    fn sum_lefts<S>(self) -> S
+        where Self: Sized,
+              S: Sum<L>
+    {
+        self.filter_map(Either::left).sum()
+    }
+
+    /// Sum the right items, ignoring the left items.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left(1), Right(2.5), Right(0.25)];
+    /// assert_eq!(values.into_iter().sum_rights::<f64>(), 2.75);
+    /// ```
+    fn sum_rights<S>(self) -> S
+        where Self: Sized,
+              S: Sum<R>
+    {
+        self.filter_map(Either::right).sum()
+    }
+
+    /// Call `fl` on each left item and `fr` on each right item, stopping at the first
+    /// error and returning it.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let mut total = 0;
+    /// let mut names = Vec::new();
+    /// let values = vec![Left(1), Right("a"), Left(2)];
+    /// let result = values.into_iter().try_for_each_partition(
+    ///     |x| if x > 0 { total += x; Ok(()) } else { Err("not positive") },
+    ///     |s| { names.push(s); Ok(()) });
+    /// assert_eq!(result, Ok(()));
+    /// assert_eq!((total, names), (3, vec!["a"]));
+    ///
+    /// let values = vec![Left(1), Left(0), Left(2)];
+    /// let mut seen = 0;
+    /// let result = values.into_iter().try_for_each_partition(
+    ///     |x| { seen += 1; if x > 0 { Ok(()) } else { Err("not positive") } },
+    ///     |_: ()| Ok(()));
+    /// assert_eq!(result, Err("not positive"));
+    /// assert_eq!(seen, 2);
+    /// ```
+    fn try_for_each_partition<E, F, G>(self, mut fl: F, mut fr: G) -> Result<(), E>
+        where Self: Sized,
+              F: FnMut(L) -> Result<(), E>,
+              G: FnMut(R) -> Result<(), E>
+    {
+        for item in self {
+            match item {
+                Either::Left(l) => fl(l)?,
+                Either::Right(r) => fr(r)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Keep only the left items, mapped with `f`, dropping the right items and the
+    /// left items for which `f` returns `None`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left("1"), Right(2.5), Left("x"), Left("3")];
+    /// let ints: Vec<i32> = values.into_iter()
+    ///                            .filter_map_left(|s| s.parse().ok())
+    ///                            .collect();
+    /// assert_eq!(ints, vec![1, 3]);
+    /// ```
+    fn filter_map_left<F, U>(self, f: F) -> FilterMapLeft<Self, F>
+        where Self: Sized,
+              F: FnMut(L) -> Option<U>
+    {
+        FilterMapLeft { iter: self, f }
+    }
+
+    /// Keep only the right items, mapped with `f`, dropping the left items and the
+    /// right items for which `f` returns `None`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Right("1"), Left(2.5), Right("x"), Right("3")];
+    /// let ints: Vec<i32> = values.into_iter()
+    ///                            .filter_map_right(|s| s.parse().ok())
+    ///                            .collect();
+    /// assert_eq!(ints, vec![1, 3]);
+    /// ```
+    fn filter_map_right<F, U>(self, f: F) -> FilterMapRight<Self, F>
+        where Self: Sized,
+              F: FnMut(R) -> Option<U>
+    {
+        FilterMapRight { iter: self, f }
+    }
+
+    /// Convert each item to a `Result`, with `Right => Ok` and `Left => Err`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values: Vec<Either<&str, u32>> = vec![Right(1), Right(2)];
+    /// assert_eq!(values.into_iter().into_results().collect::<Result<Vec<_>, _>>(),
+    ///            Ok(vec![1, 2]));
+    ///
+    /// let values = vec![Right(1), Left("error"), Right(2)];
+    /// assert_eq!(values.into_iter().into_results().collect::<Result<Vec<_>, _>>(),
+    ///            Err("error"));
+    /// ```
+    fn into_results(self) -> IntoResults<Self>
+        where Self: Sized
+    {
+        IntoResults { iter: self }
+    }
+
+    /// Group runs of consecutive items on the same side into vectors, yielding
+    /// `Left(Vec<L>)` for a run of left items and `Right(Vec<R>)` for a run of right items.
+    ///
+    /// A run ends when the side changes, so the yielded groups alternate sides. The
+    /// adapter is lazy: each group is collected when it is requested.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::IteratorExt;
+    ///
+    /// let values = vec![Left(1), Left(2), Right('a'), Left(3), Right('b'), Right('c')];
+    /// let groups: Vec<_> = values.into_iter().group_by_side().collect();
+    /// assert_eq!(groups, vec![Left(vec![1, 2]), Right(vec!['a']),
+    ///                         Left(vec![3]), Right(vec!['b', 'c'])]);
+    /// ```
+    #[cfg(any(test, feature = "use_std"))]
+    fn group_by_side(self) -> GroupBySide<Self, L, R>
+        where Self: Sized
+    {
+        GroupBySide { iter: self, pending: None }
+    }
+}
+
+impl<I, L, R> IteratorExt<L, R> for I
+    where I: Iterator<Item = Either<L, R>>
+{
+}
+
+/// Partition an iterable of fallible `Either` values into two collections,
+/// stopping at the first error.
+///
+/// The left values are collected into `A` and the right values into `B`.
+///
+/// ```
+/// use either::*;
+/// use either::iterator::try_partition_either;
+///
+/// let records: Vec<Result<Either<u32, char>, &str>> = vec![Ok(Left(1)), Ok(Right('a')), Ok(Left(2))];
+/// let (numbers, chars): (Vec<_>, String) = try_partition_either(records).unwrap();
+/// assert_eq!(numbers, vec![1, 2]);
+/// assert_eq!(chars, "a");
+///
+/// let records: Vec<Result<Either<u32, char>, &str>> = vec![Ok(Left(1)), Err("bad record")];
+/// assert_eq!(try_partition_either::<_, Vec<_>, String, _, _, _>(records), Err("bad record"));
+/// ```
+pub fn try_partition_either<I, A, B, E, L, R>(iter: I) -> Result<(A, B), E>
+    where I: IntoIterator<Item = Result<Either<L, R>, E>>,
+          A: Default + Extend<L>,
+          B: Default + Extend<R>
+{
+    let mut lefts = A::default();
+    let mut rights = B::default();
+    for item in iter {
+        match item? {
+            Either::Left(l) => lefts.extend(Some(l)),
+            Either::Right(r) => rights.extend(Some(r)),
+        }
+    }
+    Ok((lefts, rights))
+}
+
+/// Collect all the left values of an iterable of `Either` values if there are any,
+/// otherwise all the right values.
+///
+/// This is accumulating validation with `Left` as the error: every item is consumed,
+/// so all the errors are reported, and the successes are only returned if there are
+/// no errors.
+///
+/// Requires crate feature `"use_std"`
+///
+/// ```
+/// use either::*;
+/// use either::iterator::collect_validated;
+///
+/// let fields: Vec<Either<&str, u32>> = vec![Right(1), Left("bad a"), Right(2), Left("bad b")];
+/// assert_eq!(collect_validated(fields), Left(vec!["bad a", "bad b"]));
+///
+/// let fields: Vec<Either<&str, u32>> = vec![Right(1), Right(2)];
+/// assert_eq!(collect_validated(fields), Right(vec![1, 2]));
+/// ```
+#[cfg(any(test, feature = "use_std"))]
+pub fn collect_validated<I, L, R>(iter: I) -> Either<Vec<L>, Vec<R>>
+    where I: IntoIterator<Item = Either<L, R>>
+{
+    let mut lefts = Vec::new();
+    let mut rights = Vec::new();
+    for item in iter {
+        match item {
+            Either::Left(l) => {
+                if lefts.is_empty() {
+                    rights = Vec::new();
+                }
+                lefts.push(l);
+            }
+            Either::Right(r) => {
+                if lefts.is_empty() {
+                    rights.push(r);
+                }
+            }
+        }
+    }
+    if lefts.is_empty() {
+        Either::Right(rights)
+    } else {
+        Either::Left(lefts)
+    }
+}
+
+/// Map every left value of a vector of `Either` values with `f`, leaving the right
+/// values untouched.
+///
+/// The values are mapped in place, reusing the input allocation, when `Either<M, R>` has
+/// the same non-zero size and the same alignment as `Either<L, R>`, for example when `M`
+/// and `L` are the same type or two integer types of the same width. Otherwise a new
+/// vector is allocated.
+///
+/// Requires crate feature `"use_std"`
+///
+/// ```
+/// use either::*;
+/// use either::iterator::map_lefts;
+///
+/// let values: Vec<Either<u32, &str>> = vec![Left(1), Right("a"), Left(2)];
+/// assert_eq!(map_lefts(values, |x| x * 10), vec![Left(10), Right("a"), Left(20)]);
+/// ```
+#[cfg(any(test, feature = "use_std"))]
+pub fn map_lefts<L, R, M, F>(values: Vec<Either<L, R>>, mut f: F) -> Vec<Either<M, R>>
+    where F: FnMut(L) -> M
+{
+    map_in_place(values, |value| value.map_left(&mut f))
+}
+
+/// Map every right value of a vector of `Either` values with `f`, leaving the left
+/// values untouched.
+///
+/// The input allocation is reused under the same conditions as for
+/// [`map_lefts`](fn.map_lefts.html).
+///
+/// Requires crate feature `"use_std"`
+///
+/// ```
+/// use either::*;
+/// use either::iterator::map_rights;
+///
+/// let values: Vec<Either<&str, u32>> = vec![Left("a"), Right(1)];
+/// assert_eq!(map_rights(values, |x| x + 1), vec![Left("a"), Right(2)]);
+/// ```
+#[cfg(any(test, feature = "use_std"))]
+pub fn map_rights<L, R, S, F>(values: Vec<Either<L, R>>, mut f: F) -> Vec<Either<L, S>>
+    where F: FnMut(R) -> S
+{
+    map_in_place(values, |value| value.map_right(&mut f))
+}
+
+/// Map every element of `values` with `f`, writing the results over the inputs when `T`
+/// and `U` have the same non-zero size and the same alignment.
+#[cfg(any(test, feature = "use_std"))]
+fn map_in_place<T, U, F>(values: Vec<T>, mut f: F) -> Vec<U>
+    where F: FnMut(T) -> U
+{
+    if mem::size_of::<T>() != mem::size_of::<U>() || mem::align_of::<T>() != mem::align_of::<U>()
+        || mem::size_of::<T>() == 0
+    {
+        return values.into_iter().map(f).collect();
+    }
+
+    // Owns the buffer while it holds mapped values before `mapped` and inputs after it. If
+    // `f` panics, both are dropped, skipping the input that was moved into `f`.
+    struct Guard<T, U> {
+        ptr: *mut T,
+        len: usize,
+        cap: usize,
+        mapped: usize,
+        marker: PhantomData<U>,
+    }
+
+    impl<T, U> Drop for Guard<T, U> {
+        fn drop(&mut self) {
+            unsafe {
+                for i in 0..self.mapped {
+                    ptr::drop_in_place((self.ptr as *mut U).add(i));
+                }
+                for i in self.mapped + 1..self.len {
+                    ptr::drop_in_place(self.ptr.add(i));
+                }
+                drop(Vec::from_raw_parts(self.ptr, 0, self.cap));
+            }
+        }
+    }
+
+    let mut values = values;
+    let mut guard = Guard::<T, U> {
+        ptr: values.as_mut_ptr(),
+        len: values.len(),
+        cap: values.capacity(),
+        mapped: 0,
+        marker: PhantomData,
+    };
+    mem::forget(values);
+    while guard.mapped < guard.len {
+        // Safety: the element at `mapped` is an initialized input, and a `U` fits in its slot
+        // because `T` and `U` have the same size and alignment.
+        unsafe {
+            let slot = guard.ptr.add(guard.mapped);
+            let output = f(ptr::read(slot));
+            ptr::write(slot as *mut U, output);
+        }
+        guard.mapped += 1;
+    }
+    let (ptr, len, cap) = (guard.ptr as *mut U, guard.len, guard.cap);
+    mem::forget(guard);
+    // Safety: every element is now a `U`, and the buffer's layout is the same for `U` as for
+    // `T`.
+    unsafe { Vec::from_raw_parts(ptr, len, cap) }
+}
+
+/// Append the values of an iterable of `Either` values to two existing collections,
+/// the left values to `lefts` and the right values to `rights`.
+///
+/// ```
+/// use either::*;
+/// use either::iterator::collect_either_into;
+/// use std::collections::HashSet;
+///
+/// let first: Vec<Either<u32, &str>> = vec![Left(1), Right("a"), Left(2)];
+/// let second: Vec<Either<u32, &str>> = vec![Right("a"), Right("b")];
+///
+/// let mut numbers = vec![0];
+/// let mut words = HashSet::new();
+/// collect_either_into(first, &mut numbers, &mut words);
+/// collect_either_into(second, &mut numbers, &mut words);
+/// assert_eq!(numbers, vec![0, 1, 2]);
+/// assert_eq!(words, ["a", "b"].iter().cloned().collect::<HashSet<_>>());
+/// ```
+pub fn collect_either_into<I, A, B, L, R>(iter: I, lefts: &mut A, rights: &mut B)
+    where I: IntoIterator<Item = Either<L, R>>,
+          A: Extend<L>,
+          B: Extend<R>
+{
+    for item in iter {
+        match item {
+            Either::Left(l) => lefts.extend(Some(l)),
+            Either::Right(r) => rights.extend(Some(r)),
+        }
+    }
+}
+
+/// Reorder a slice of `Either` values in place so that all the `Left` values come
+/// before all the `Right` values, and return the number of `Left` values.
+///
+/// The partition is not stable: values are swapped pairwise from both ends of the
+/// slice, so the relative order within each side is not preserved. It runs in linear
+/// time and does not allocate.
+///
+/// ```
+/// use either::*;
+/// use either::iterator::partition_in_place_by_side;
+///
+/// let mut values = [Right('a'), Left(1), Right('b'), Left(2), Left(3)];
+/// let pivot = partition_in_place_by_side(&mut values);
+/// assert_eq!(pivot, 3);
+/// assert!(values[..pivot].iter().all(Either::is_left));
+/// assert!(values[pivot..].iter().all(Either::is_right));
+/// ```
+pub fn partition_in_place_by_side<L, R>(slice: &mut [Either<L, R>]) -> usize {
+    let mut start = 0;
+    let mut end = slice.len();
+    loop {
+        while start < end && slice[start].is_left() {
+            start += 1;
+        }
+        while start < end && slice[end - 1].is_right() {
+            end -= 1;
+        }
+        if start == end {
+            return start;
+        }
+        slice.swap(start, end - 1);
+        start += 1;
+        end -= 1;
+    }
+}
+
+/// Find the first `Left` value in a slice of `Either` values, returning its index and
+/// a reference to it.
+///
+/// ```
+/// use either::*;
+/// use either::iterator::{find_left, find_right};
+///
+/// let values = [Right("a"), Left(1), Left(2)];
+/// assert_eq!(find_left(&values), Some((1, &1)));
+/// assert_eq!(find_right(&values), Some((0, &"a")));
+/// assert_eq!(find_right(&values[1..]), None);
+/// ```
+pub fn find_left<L, R>(slice: &[Either<L, R>]) -> Option<(usize, &L)> {
+    slice.iter().enumerate().find_map(|(i, item)| item.as_ref().left().map(|l| (i, l)))
+}
+
+/// Find the first `Right` value in a slice of `Either` values, returning its index and
+/// a reference to it.
+///
+/// See [`find_left`](fn.find_left.html) for an example.
+pub fn find_right<L, R>(slice: &[Either<L, R>]) -> Option<(usize, &R)> {
+    slice.iter().enumerate().find_map(|(i, item)| item.as_ref().right().map(|r| (i, r)))
+}
+
+/// Find the first `Left` value in a slice of `Either` values, returning its index and
+/// a mutable reference to it.
+///
+/// ```
+/// use either::*;
+/// use either::iterator::{find_left_mut, find_right_mut};
+///
+/// let mut values = [Right('a'), Left(1), Right('b')];
+/// if let Some((_, l)) = find_left_mut(&mut values) {
+///     *l += 10;
+/// }
+/// if let Some((i, r)) = find_right_mut(&mut values) {
+///     assert_eq!(i, 0);
+///     *r = 'z';
+/// }
+/// assert_eq!(values, [Right('z'), Left(11), Right('b')]);
+/// ```
+pub fn find_left_mut<L, R>(slice: &mut [Either<L, R>]) -> Option<(usize, &mut L)> {
+    slice.iter_mut().enumerate().find_map(|(i, item)| item.as_mut().left().map(|l| (i, l)))
+}
+
+/// Find the first `Right` value in a slice of `Either` values, returning its index and
+/// a mutable reference to it.
+///
+/// See [`find_left_mut`](fn.find_left_mut.html) for an example.
+pub fn find_right_mut<L, R>(slice: &mut [Either<L, R>]) -> Option<(usize, &mut R)> {
+    slice.iter_mut().enumerate().find_map(|(i, item)| item.as_mut().right().map(|r| (i, r)))
+}
+
+/// An iterator that maps the left items of an iterator of `Either` and filters out
+/// everything else.
+///
+/// Created with [`IteratorExt::filter_map_left`](trait.IteratorExt.html#method.filter_map_left).
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FilterMapLeft<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> ::std::fmt::Debug for FilterMapLeft<I, F>
+    where I: ::std::fmt::Debug
+{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("FilterMapLeft").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, F, L, R, U> Iterator for FilterMapLeft<I, F>
+    where I: Iterator<Item = Either<L, R>>,
+          F: FnMut(L) -> Option<U>
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        for item in &mut self.iter {
+            if let Either::Left(l) = item {
+                if let Some(u) = (self.f)(l) {
+                    return Some(u);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F, L, R, U> DoubleEndedIterator for FilterMapLeft<I, F>
+    where I: DoubleEndedIterator<Item = Either<L, R>>,
+          F: FnMut(L) -> Option<U>
+{
+    fn next_back(&mut self) -> Option<U> {
+        while let Some(item) = self.iter.next_back() {
+            if let Either::Left(l) = item {
+                if let Some(u) = (self.f)(l) {
+                    return Some(u);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An iterator that maps the right items of an iterator of `Either` and filters out
+/// everything else.
+///
+/// Created with [`IteratorExt::filter_map_right`](trait.IteratorExt.html#method.filter_map_right).
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct FilterMapRight<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> ::std::fmt::Debug for FilterMapRight<I, F>
+    where I: ::std::fmt::Debug
+{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("FilterMapRight").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, F, L, R, U> Iterator for FilterMapRight<I, F>
+    where I: Iterator<Item = Either<L, R>>,
+          F: FnMut(R) -> Option<U>
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        for item in &mut self.iter {
+            if let Either::Right(r) = item {
+                if let Some(u) = (self.f)(r) {
+                    return Some(u);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F, L, R, U> DoubleEndedIterator for FilterMapRight<I, F>
+    where I: DoubleEndedIterator<Item = Either<L, R>>,
+          F: FnMut(R) -> Option<U>
+{
+    fn next_back(&mut self) -> Option<U> {
+        while let Some(item) = self.iter.next_back() {
+            if let Either::Right(r) = item {
+                if let Some(u) = (self.f)(r) {
+                    return Some(u);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An iterator that converts the items of an iterator of `Either` to `Result`.
+///
+/// Created with [`IteratorExt::into_results`](trait.IteratorExt.html#method.into_results).
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntoResults<I> {
+    iter: I,
+}
+
+impl<I, L, R> Iterator for IntoResults<I>
+    where I: Iterator<Item = Either<L, R>>
+{
+    type Item = Result<R, L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Into::into)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, L, R> DoubleEndedIterator for IntoResults<I>
+    where I: DoubleEndedIterator<Item = Either<L, R>>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Into::into)
+    }
+}
+
+impl<I, L, R> ExactSizeIterator for IntoResults<I>
+    where I: ExactSizeIterator<Item = Either<L, R>>
+{
+}
+
+/// An iterator that groups runs of consecutive items on the same side of an iterator
+/// of `Either`.
+///
+/// Created with [`IteratorExt::group_by_side`](trait.IteratorExt.html#method.group_by_side).
+///
+/// Requires crate feature `"use_std"`
+#[cfg(any(test, feature = "use_std"))]
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct GroupBySide<I, L, R> {
+    iter: I,
+    pending: Option<Either<L, R>>,
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<I, L, R> Iterator for GroupBySide<I, L, R>
+    where I: Iterator<Item = Either<L, R>>
+{
+    type Item = Either<Vec<L>, Vec<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take() {
+            Some(item) => item,
+            None => self.iter.next()?,
+        };
+        match first {
+            Either::Left(l) => {
+                let mut group = vec![l];
+                for item in &mut self.iter {
+                    match item {
+                        Either::Left(l) => group.push(l),
+                        right => {
+                            self.pending = Some(right);
+                            break;
+                        }
+                    }
+                }
+                Some(Either::Left(group))
+            }
+            Either::Right(r) => {
+                let mut group = vec![r];
+                for item in &mut self.iter {
+                    match item {
+                        Either::Right(r) => group.push(r),
+                        left => {
+                            self.pending = Some(left);
+                            break;
+                        }
+                    }
+                }
+                Some(Either::Right(group))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (low, high) = self.iter.size_hint();
+        let low = if pending + low > 0 { 1 } else { 0 };
+        (low, high.and_then(|h| h.checked_add(pending)))
+    }
+}
+
+/// A boxed iterator, used to unify an `Either` of iterators with other
+/// iterator types behind a single concrete type.
+///
+/// Created with [`Either::dynamic`](../enum.Either.html#method.dynamic) or
+/// [`DynEither::new`](#method.new).
+///
+/// Requires crate feature `"use_std"`
+#[cfg(any(test, feature = "use_std"))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DynEither<'a, T> {
+    iter: Box<dyn Iterator<Item = T> + 'a>,
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> DynEither<'a, T> {
+    /// Box any iterator with items of type `T`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::DynEither;
+    ///
+    /// fn numbers(kind: u8) -> DynEither<'static, u32> {
+    ///     if kind > 1 {
+    ///         return DynEither::new(std::iter::once(42));
+    ///     }
+    ///     let iter = if kind == 0 { Left(0..3) } else { Right(vec![7, 8].into_iter()) };
+    ///     iter.dynamic()
+    /// }
+    ///
+    /// assert_eq!(numbers(0).collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// assert_eq!(numbers(1).collect::<Vec<_>>(), vec![7, 8]);
+    /// assert_eq!(numbers(2).collect::<Vec<_>>(), vec![42]);
+    /// ```
+    pub fn new<I>(iter: I) -> Self
+        where I: Iterator<Item = T> + 'a
+    {
+        DynEither { iter: Box::new(iter) }
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> Iterator for DynEither<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> ::std::fmt::Debug for DynEither<'a, T> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("DynEither").finish()
+    }
+}
+
+/// A boxed double-ended iterator, the double-ended counterpart of
+/// [`DynEither`](struct.DynEither.html).
+///
+/// Created with
+/// [`Either::dynamic_double_ended`](../enum.Either.html#method.dynamic_double_ended) or
+/// [`DynEitherDoubleEnded::new`](#method.new).
+///
+/// Requires crate feature `"use_std"`
+#[cfg(any(test, feature = "use_std"))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DynEitherDoubleEnded<'a, T> {
+    iter: Box<dyn DoubleEndedIterator<Item = T> + 'a>,
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> DynEitherDoubleEnded<'a, T> {
+    /// Box any double-ended iterator with items of type `T`.
+    ///
+    /// ```
+    /// use either::*;
+    /// use either::iterator::DynEitherDoubleEnded;
+    ///
+    /// fn numbers(kind: u8) -> DynEitherDoubleEnded<'static, u32> {
+    ///     if kind > 1 {
+    ///         return DynEitherDoubleEnded::new(std::iter::once(42));
+    ///     }
+    ///     let iter = if kind == 0 { Left(0..3) } else { Right(vec![7, 8].into_iter()) };
+    ///     iter.dynamic_double_ended()
+    /// }
+    ///
+    /// assert_eq!(numbers(0).rev().collect::<Vec<_>>(), vec![2, 1, 0]);
+    /// assert_eq!(numbers(1).rev().collect::<Vec<_>>(), vec![8, 7]);
+    /// assert_eq!(numbers(2).next_back(), Some(42));
+    /// ```
+    pub fn new<I>(iter: I) -> Self
+        where I: DoubleEndedIterator<Item = T> + 'a
+    {
+        DynEitherDoubleEnded { iter: Box::new(iter) }
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> Iterator for DynEitherDoubleEnded<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> DoubleEndedIterator for DynEitherDoubleEnded<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, T> ::std::fmt::Debug for DynEitherDoubleEnded<'a, T> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("DynEitherDoubleEnded").finish()
+    }
+}
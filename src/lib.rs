@@ -3,13 +3,26 @@
 //!
 //! [`Either`]: enum.Either.html
 //!
+//! **Rust version:** requires Rust 1.46 or later.
+//!
 //! **Crate features:**
 //!
 //! * `"use_std"`
-//! Enabled by default. Disable to make the library `#![no_std]`.
+//!   Enabled by default. Disable to make the library `#![no_std]`.
 //!
 //! * `"serde"`
-//! Disabled by default. Enable to `#[derive(Serialize, Deserialize)]` for `Either`
+//!   Disabled by default. Enable to `#[derive(Serialize, Deserialize)]` for `Either`,
+//!   and for the [`serde_untagged`](serde_untagged/index.html) and
+//!   [`serde_untagged_optional`](serde_untagged_optional/index.html) field helpers.
+//!
+//! * `"future"`
+//!   Disabled by default. Enable for `map_left_fut` and `map_right_fut`, which map one side
+//!   of an `Either` with an asynchronous function, and `await_either`, which awaits an
+//!   `Either` of futures.
+//!
+//! * `"is_terminal"`
+//!   Disabled by default. Enable for `is_terminal`, which forwards `std::io::IsTerminal`.
+//!   Requires Rust 1.70 or later. Implies `"use_std"`.
 //!
 
 #![doc(html_root_url = "https://docs.rs/either/1/")]
@@ -26,26 +39,58 @@ extern crate core as std;
 #[macro_use]
 extern crate serde;
 
-use std::convert::{AsRef, AsMut};
+use std::convert::{AsRef, AsMut, TryInto};
 use std::fmt;
 use std::iter;
+use std::mem;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+use std::ptr;
+use std::str::FromStr;
+#[cfg(any(test, feature = "use_std"))]
+use std::any::Any;
+#[cfg(any(test, feature = "use_std"))]
+use std::borrow::Cow;
 #[cfg(any(test, feature = "use_std"))]
 use std::io::{self, Write, Read, BufRead};
+#[cfg(feature = "is_terminal")]
+use std::io::IsTerminal;
+#[cfg(any(test, feature = "use_std"))]
+use std::net::ToSocketAddrs;
 #[cfg(all(feature = "use_std", feature = "try_trait"))]
 use std::ops::Try;
 #[cfg(any(test, feature = "use_std"))]
 use std::error::Error;
+#[cfg(feature = "future")]
+use std::future::Future;
+#[cfg(feature = "future")]
+use std::pin::Pin;
+#[cfg(feature = "future")]
+use std::task::{Context, Poll};
 
 pub use Either::{Left, Right};
 
+#[cfg(rustc_1_55)]
+mod array;
+pub mod iterator;
+#[cfg(feature = "serde")]
+pub mod serde_untagged;
+#[cfg(feature = "serde")]
+pub mod serde_untagged_optional;
+
 /// The enum `Either` with variants `Left` and `Right` is a general purpose
 /// sum type with two cases.
 ///
 /// The `Either` type is symmetric and treats its variants the same way, without
 /// preference.
 /// (For representing success or error, use the regular `Result` enum instead.)
+///
+/// The derived `Hash`, `Eq` and `Ord` implementations take both the variant and
+/// the value into account, so `Either` can be used as a `HashMap` or `BTreeMap`
+/// key whenever `L` and `R` can. `Left` values order before all `Right` values.
+/// Since `Borrow<Either<L, R>>` is implemented through the blanket impl only,
+/// look up such a map with an owned `Either` key.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum Either<L, R> {
@@ -55,6 +100,34 @@ pub enum Either<L, R> {
     Right(R),
 }
 
+/// The side of an `Either`, without its payload.
+///
+/// See [`Either::side`](enum.Either.html#method.side).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Side {
+    /// The `Left` variant.
+    Left,
+    /// The `Right` variant.
+    Right,
+}
+
+/// The combined values of two `Either`s, with one variant for each pair of sides.
+///
+/// See [`Either::zip4`](enum.Either.html#method.zip4).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum EitherZip<LA, RA, LB, RB> {
+    /// Both values were `Left`.
+    LeftLeft(LA, LB),
+    /// The first value was `Left` and the second `Right`.
+    LeftRight(LA, RB),
+    /// The first value was `Right` and the second `Left`.
+    RightLeft(RA, LB),
+    /// Both values were `Right`.
+    RightRight(RA, RB),
+}
+
 macro_rules! either {
     ($value:expr, $pattern:pat => $result:expr) => (
         match $value {
@@ -108,6 +181,51 @@ macro_rules! try_right {
     )
 }
 
+/// Macro for borrowing the value of an `Either` as a trait object of a trait
+/// that both sides implement.
+///
+/// The expression can be an `Either` or a reference to one; the trait object
+/// borrows from it.
+///
+/// # Example
+///
+/// ```
+/// #[macro_use] extern crate either;
+/// use either::{Either, Left, Right};
+///
+/// trait Shape {
+///     fn area(&self) -> f64;
+/// }
+///
+/// struct Square(f64);
+/// struct Rect(f64, f64);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 { self.0 * self.0 }
+/// }
+///
+/// impl Shape for Rect {
+///     fn area(&self) -> f64 { self.0 * self.1 }
+/// }
+///
+/// fn main() {
+///     let square: Either<Square, Rect> = Left(Square(2.0));
+///     let rect: Either<Square, Rect> = Right(Rect(2.0, 3.0));
+///
+///     assert_eq!(either_ref_as_dyn!(square => Shape).area(), 4.0);
+///     assert_eq!(either_ref_as_dyn!(&rect => Shape).area(), 6.0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! either_ref_as_dyn {
+    ($expr:expr => $trait:path) => (
+        match $expr {
+            $crate::Left(ref inner) => inner as &dyn $trait,
+            $crate::Right(ref inner) => inner as &dyn $trait,
+        }
+    )
+}
+
 impl<L, R> Either<L, R> {
     /// Return true if the value is the `Left` variant.
     ///
@@ -138,8 +256,102 @@ impl<L, R> Either<L, R> {
         !self.is_left()
     }
 
+    /// Return which side the value is on, as a `Side`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values = [Left(1), Right("the right value")];
+    /// assert_eq!(values[0].side(), Side::Left);
+    /// assert_eq!(values[1].side(), Side::Right);
+    /// ```
+    pub fn side(&self) -> Side {
+        match *self {
+            Left(_) => Side::Left,
+            Right(_) => Side::Right,
+        }
+    }
+
+    /// Return true if both values are on the same side, without comparing their contents.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let a: Either<u32, &str> = Left(1);
+    /// assert!(a.same_side(&Left(2)));
+    /// assert!(!a.same_side(&Right("the right value")));
+    /// ```
+    pub fn same_side(&self, other: &Either<L, R>) -> bool {
+        self.side() == other.side()
+    }
+
+    /// Return a numeric tag for the variant: `0` for `Left` and `1` for `Right`.
+    ///
+    /// The tag values are stable and can be shared with foreign code. Note that this does
+    /// *not* describe the memory layout: `Either` has the default Rust representation, so its
+    /// in-memory discriminant is unspecified and an `Either` must not be passed across an FFI
+    /// boundary directly. Pass the tag and the inner value separately instead.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values: [Either<*mut u8, *mut u16>; 2] = [
+    ///     Left(std::ptr::null_mut()),
+    ///     Right(std::ptr::null_mut()),
+    /// ];
+    /// assert_eq!(values[0].tag(), 0);
+    /// assert_eq!(values[1].tag(), 1);
+    /// ```
+    pub fn tag(&self) -> u8 {
+        match *self {
+            Left(_) => 0,
+            Right(_) => 1,
+        }
+    }
+
+    /// Return `self` unchanged, checking in debug builds that it is a `Left`.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, panics if the value is a `Right`. Otherwise this is a
+    /// no-op.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left(123);
+    /// assert_eq!(left.debug_assert_left().map_left(|x| x * 2), Left(246));
+    /// ```
+    #[track_caller]
+    pub fn debug_assert_left(self) -> Self {
+        debug_assert!(self.is_left(), "called `Either::debug_assert_left()` on a `Right` value");
+        self
+    }
+
+    /// Return `self` unchanged, checking in debug builds that it is a `Right`.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, panics if the value is a `Left`. Otherwise this is a
+    /// no-op.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<(), _> = Right(123);
+    /// assert_eq!(right.debug_assert_right().map_right(|x| x * 2), Right(246));
+    /// ```
+    #[track_caller]
+    pub fn debug_assert_right(self) -> Self {
+        debug_assert!(self.is_right(), "called `Either::debug_assert_right()` on a `Left` value");
+        self
+    }
+
     /// Convert the left side of `Either<L, R>` to an `Option<L>`.
     ///
+    /// This discards a right value; use [`left_result`](#method.left_result) to get it
+    /// back as the error of a `Result<L, R>` instead.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -158,6 +370,9 @@ impl<L, R> Either<L, R> {
 
     /// Convert the right side of `Either<L, R>` to an `Option<R>`.
     ///
+    /// This discards a left value; use [`right_result`](#method.right_result) to get it
+    /// back as the error of a `Result<R, L>` instead.
+    ///
     /// ```
     /// use either::*;
     ///
@@ -174,234 +389,2257 @@ impl<L, R> Either<L, R> {
         }
     }
 
-    /// Convert `&Either<L, R>` to `Either<&L, &R>`.
+    /// Convert the left side of `Either<L, R>` to `Ok`, keeping a right value as the error.
+    ///
+    /// This is the non-lossy form of [`left`](#method.left), and the same conversion as
+    /// [`into_ok_left`](#method.into_ok_left).
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, ()> = Left("some value");
-    /// assert_eq!(left.as_ref(), Left(&"some value"));
+    /// let left: Either<_, &str> = Left(123);
+    /// assert_eq!(left.left_result(), Ok(123));
     ///
-    /// let right: Either<(), _> = Right("some value");
-    /// assert_eq!(right.as_ref(), Right(&"some value"));
+    /// let right: Either<u32, _> = Right("not a number");
+    /// assert_eq!(right.left_result(), Err("not a number"));
     /// ```
-    pub fn as_ref(&self) -> Either<&L, &R> {
-        match *self {
-            Left(ref inner) => Left(inner),
-            Right(ref inner) => Right(inner),
-        }
+    #[inline]
+    pub fn left_result(self) -> Result<L, R> {
+        self.into_ok_left()
     }
 
-    /// Convert `&mut Either<L, R>` to `Either<&mut L, &mut R>`.
+    /// Convert the right side of `Either<L, R>` to `Ok`, keeping a left value as the error.
+    ///
+    /// This is the non-lossy form of [`right`](#method.right), and the same conversion as
+    /// [`into_ok_right`](#method.into_ok_right).
     ///
     /// ```
     /// use either::*;
     ///
-    /// fn mutate_left(value: &mut Either<u32, u32>) {
-    ///     if let Some(l) = value.as_mut().left() {
-    ///         *l = 999;
-    ///     }
-    /// }
+    /// let left: Either<_, u32> = Left("not a number");
+    /// assert_eq!(left.right_result(), Err("not a number"));
     ///
-    /// let mut left = Left(123);
-    /// let mut right = Right(123);
-    /// mutate_left(&mut left);
-    /// mutate_left(&mut right);
-    /// assert_eq!(left, Left(999));
-    /// assert_eq!(right, Right(123));
+    /// let right: Either<&str, _> = Right(123);
+    /// assert_eq!(right.right_result(), Ok(123));
     /// ```
-    pub fn as_mut(&mut self) -> Either<&mut L, &mut R> {
-        match *self {
-            Left(ref mut inner) => Left(inner),
-            Right(ref mut inner) => Right(inner),
-        }
+    #[inline]
+    pub fn right_result(self) -> Result<R, L> {
+        self.into_ok_right()
     }
 
-    /// Convert `Either<L, R>` to `Either<R, L>`.
+    /// Borrow the left side of `Either<L, R>` as an `Option<&L>`.
+    ///
+    /// This is the same as `self.as_ref().left()`, matching on `self` directly.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, ()> = Left(123);
-    /// assert_eq!(left.flip(), Right(123));
+    /// let left: Either<_, ()> = Left("some value");
+    /// assert_eq!(left.left_ref(), Some(&"some value"));
     ///
-    /// let right: Either<(), _> = Right("some value");
-    /// assert_eq!(right.flip(), Left("some value"));
+    /// let right: Either<(), _> = Right(321);
+    /// assert_eq!(right.left_ref(), None);
     /// ```
-    pub fn flip(self) -> Either<R, L> {
-        match self {
-            Left(l) => Right(l),
-            Right(r) => Left(r),
+    #[inline]
+    pub fn left_ref(&self) -> Option<&L> {
+        match *self {
+            Left(ref l) => Some(l),
+            Right(_) => None,
         }
     }
 
-    /// Apply the function `f` on the value in the `Left` variant if it is present rewrapping the
-    /// result in `Left`.
+    /// Borrow the right side of `Either<L, R>` as an `Option<&R>`.
+    ///
+    /// This is the same as `self.as_ref().right()`, matching on `self` directly.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, u32> = Left(123);
-    /// assert_eq!(left.map_left(|x| x * 2), Left(246));
-    ///
-    /// let right: Either<u32, _> = Right(123);
-    /// assert_eq!(right.map_left(|x| x * 2), Right(123));
+    /// let right: Either<(), _> = Right(321);
+    /// assert_eq!(right.right_ref(), Some(&321));
     /// ```
-    pub fn map_left<F, M>(self, f: F) -> Either<M, R>
-        where F: FnOnce(L) -> M
-    {
-        match self {
-            Left(l) => Left(f(l)),
-            Right(r) => Right(r),
+    #[inline]
+    pub fn right_ref(&self) -> Option<&R> {
+        match *self {
+            Left(_) => None,
+            Right(ref r) => Some(r),
         }
     }
 
-    /// Apply the function `f` on the value in the `Right` variant if it is present rewrapping the
-    /// result in `Right`.
+    /// Mutably borrow the left side of `Either<L, R>` as an `Option<&mut L>`.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, u32> = Left(123);
-    /// assert_eq!(left.map_right(|x| x * 2), Left(123));
-    ///
-    /// let right: Either<u32, _> = Right(123);
-    /// assert_eq!(right.map_right(|x| x * 2), Right(246));
+    /// let mut left: Either<_, ()> = Left(123);
+    /// if let Some(l) = left.left_mut() {
+    ///     *l += 1;
+    /// }
+    /// assert_eq!(left, Left(124));
     /// ```
-    pub fn map_right<F, S>(self, f: F) -> Either<L, S>
-        where F: FnOnce(R) -> S
-    {
-        match self {
-            Left(l) => Left(l),
-            Right(r) => Right(f(r)),
+    #[inline]
+    pub fn left_mut(&mut self) -> Option<&mut L> {
+        match *self {
+            Left(ref mut l) => Some(l),
+            Right(_) => None,
         }
     }
 
-    /// Apply one of two functions depending on contents, unifying their result. If the value is
-    /// `Left(L)` then the first function `f` is applied; if it is `Right(R)` then the second
-    /// function `g` is applied.
+    /// Mutably borrow the right side of `Either<L, R>` as an `Option<&mut R>`.
     ///
     /// ```
     /// use either::*;
     ///
-    /// fn square(n: u32) -> i32 { (n * n) as i32 }
-    /// fn negate(n: i32) -> i32 { -n }
-    ///
-    /// let left: Either<u32, i32> = Left(4);
-    /// assert_eq!(left.either(square, negate), 16);
-    ///
-    /// let right: Either<u32, i32> = Right(-4);
-    /// assert_eq!(right.either(square, negate), 4);
+    /// let mut right: Either<(), _> = Right(123);
+    /// if let Some(r) = right.right_mut() {
+    ///     *r += 1;
+    /// }
+    /// assert_eq!(right, Right(124));
     /// ```
-    pub fn either<F, G, T>(self, f: F, g: G) -> T
-      where F: FnOnce(L) -> T,
-            G: FnOnce(R) -> T
-    {
-        match self {
-            Left(l) => f(l),
-            Right(r) => g(r),
+    #[inline]
+    pub fn right_mut(&mut self) -> Option<&mut R> {
+        match *self {
+            Left(_) => None,
+            Right(ref mut r) => Some(r),
         }
     }
 
-    /// Like `either`, but provide some context to whichever of the
-    /// functions ends up being called.
+    /// Convert the left side of `Either<L, R>` to a `Result<L, ()>`, discarding a right value.
     ///
     /// ```
-    /// // In this example, the context is a mutable reference
     /// use either::*;
     ///
-    /// let mut result = Vec::new();
-    ///
-    /// let values = vec![Left(2), Right(2.7)];
-    ///
-    /// for value in values {
-    ///     value.either_with(&mut result,
-    ///                       |ctx, integer| ctx.push(integer),
-    ///                       |ctx, real| ctx.push(f64::round(real) as i32));
-    /// }
+    /// let left: Either<_, u32> = Left("some value");
+    /// assert_eq!(left.left_ok(), Ok("some value"));
     ///
-    /// assert_eq!(result, vec![2, 3]);
+    /// let right: Either<&str, _> = Right(321);
+    /// assert_eq!(right.left_ok(), Err(()));
     /// ```
-    pub fn either_with<Ctx, F, G, T>(self, ctx: Ctx, f: F, g: G) -> T
-      where F: FnOnce(Ctx, L) -> T,
-            G: FnOnce(Ctx, R) -> T
-    {
+    #[allow(clippy::result_unit_err)]
+    pub fn left_ok(self) -> Result<L, ()> {
         match self {
-            Left(l) => f(ctx, l),
-            Right(r) => g(ctx, r),
+            Left(l) => Ok(l),
+            Right(_) => Err(()),
         }
     }
 
-    /// Apply the function `f` on the value in the `Left` variant if it is present.
+    /// Convert the right side of `Either<L, R>` to a `Result<R, ()>`, discarding a left value.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, u32> = Left(123);
-    /// assert_eq!(left.left_and_then::<_,()>(|x| Right(x * 2)), Right(246));
+    /// let left: Either<_, u32> = Left("some value");
+    /// assert_eq!(left.right_ok(), Err(()));
     ///
-    /// let right: Either<u32, _> = Right(123);
-    /// assert_eq!(right.left_and_then(|x| Right::<(), _>(x * 2)), Right(123));
+    /// let right: Either<&str, _> = Right(321);
+    /// assert_eq!(right.right_ok(), Ok(321));
     /// ```
-    pub fn left_and_then<F, S>(self, f: F) -> Either<S, R>
-        where F: FnOnce(L) -> Either<S, R>
-    {
+    #[allow(clippy::result_unit_err)]
+    pub fn right_ok(self) -> Result<R, ()> {
         match self {
-            Left(l) => f(l),
-            Right(r) => Right(r),
+            Left(_) => Err(()),
+            Right(r) => Ok(r),
         }
     }
 
-    /// Apply the function `f` on the value in the `Right` variant if it is present.
+    /// Convert `&Either<L, R>` to a pair of optional references, of which exactly one is
+    /// `Some`.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, u32> = Left(123);
-    /// assert_eq!(left.right_and_then(|x| Right(x * 2)), Left(123));
+    /// let left: Either<_, ()> = Left("some value");
+    /// assert_eq!(left.split_ref(), (Some(&"some value"), None));
     ///
-    /// let right: Either<u32, _> = Right(123);
-    /// assert_eq!(right.right_and_then(|x| Right(x * 2)), Right(246));
+    /// let right: Either<(), _> = Right(321);
+    /// assert_eq!(right.split_ref(), (None, Some(&321)));
     /// ```
-    pub fn right_and_then<F, S>(self, f: F) -> Either<L, S>
-        where F: FnOnce(R) -> Either<L, S>
-    {
-        match self {
-            Left(l) => Left(l),
-            Right(r) => f(r),
+    pub fn split_ref(&self) -> (Option<&L>, Option<&R>) {
+        match *self {
+            Left(ref l) => (Some(l), None),
+            Right(ref r) => (None, Some(r)),
         }
     }
 
-    /// Convert the inner value to an iterator.
+    /// Convert `&mut Either<L, R>` to a pair of optional mutable references, of which exactly
+    /// one is `Some`.
     ///
     /// ```
     /// use either::*;
     ///
-    /// let left: Either<_, Vec<u32>> = Left(vec![1, 2, 3, 4, 5]);
-    /// let mut right: Either<Vec<u32>, _> = Right(vec![]);
-    /// right.extend(left.into_iter());
-    /// assert_eq!(right, Right(vec![1, 2, 3, 4, 5]));
+    /// let mut value: Either<u32, String> = Left(123);
+    /// let (left, right) = value.split_mut();
+    /// if let Some(l) = left {
+    ///     *l = 999;
+    /// }
+    /// assert!(right.is_none());
+    /// assert_eq!(value, Left(999));
     /// ```
-    pub fn into_iter(self) -> Either<L::IntoIter, R::IntoIter>
-        where L: IntoIterator,
-              R: IntoIterator<Item = L::Item>
-    {
-        match self {
-            Left(l) => Left(l.into_iter()),
-            Right(r) => Right(r.into_iter()),
+    pub fn split_mut(&mut self) -> (Option<&mut L>, Option<&mut R>) {
+        match *self {
+            Left(ref mut l) => (Some(l), None),
+            Right(ref mut r) => (None, Some(r)),
         }
     }
-}
 
-impl<T, L, R> Either<(T, L), (T, R)> {
-    /// Factor out a homogeneous type from an either of pairs.
-    ///
-    /// Here, the homogeneous type is the first element of the pairs.
+    /// Convert `&Either<L, R>` to `Either<&L, &R>`.
     ///
     /// ```
     /// use either::*;
-    /// let left: Either<_, (u32, String)> = Left((123, vec![0]));
-    /// assert_eq!(left.factor_first().0, 123);
+    ///
+    /// let left: Either<_, ()> = Left("some value");
+    /// assert_eq!(left.as_ref(), Left(&"some value"));
+    ///
+    /// let right: Either<(), _> = Right("some value");
+    /// assert_eq!(right.as_ref(), Right(&"some value"));
+    /// ```
+    pub fn as_ref(&self) -> Either<&L, &R> {
+        match *self {
+            Left(ref inner) => Left(inner),
+            Right(ref inner) => Right(inner),
+        }
+    }
+
+    /// Alias of [`as_ref`](#method.as_ref) with the lifetime of the returned references
+    /// spelled out, for generic code that names it.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn borrow_left<'a, L, R>(value: &'a Either<L, R>) -> Option<&'a L> {
+    ///     value.as_ref_lt().left()
+    /// }
+    ///
+    /// let value: Either<String, u32> = Left(String::from("name"));
+    /// assert_eq!(borrow_left(&value).map(String::as_str), Some("name"));
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn as_ref_lt<'a>(&'a self) -> Either<&'a L, &'a R> {
+        self.as_ref()
+    }
+
+    /// Convert `&mut Either<L, R>` to `Either<&mut L, &mut R>`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn mutate_left(value: &mut Either<u32, u32>) {
+    ///     if let Some(l) = value.as_mut().left() {
+    ///         *l = 999;
+    ///     }
+    /// }
+    ///
+    /// let mut left = Left(123);
+    /// let mut right = Right(123);
+    /// mutate_left(&mut left);
+    /// mutate_left(&mut right);
+    /// assert_eq!(left, Left(999));
+    /// assert_eq!(right, Right(123));
+    /// ```
+    pub fn as_mut(&mut self) -> Either<&mut L, &mut R> {
+        match *self {
+            Left(ref mut inner) => Left(inner),
+            Right(ref mut inner) => Right(inner),
+        }
+    }
+
+    /// Alias of [`as_ref`](#method.as_ref), named after structural projection.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left("some value");
+    /// assert_eq!(left.project_ref(), Left(&"some value"));
+    /// ```
+    pub fn project_ref(&self) -> Either<&L, &R> {
+        self.as_ref()
+    }
+
+    /// Alias of [`as_mut`](#method.as_mut), named after structural projection.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<u32, ()> = Left(123);
+    /// if let Left(l) = value.project_mut() {
+    ///     *l = 999;
+    /// }
+    /// assert_eq!(value, Left(999));
+    /// ```
+    pub fn project_mut(&mut self) -> Either<&mut L, &mut R> {
+        self.as_mut()
+    }
+
+    /// Dereference each side to its own target, converting `&Either<L, R>` to
+    /// `Either<&L::Target, &R::Target>`.
+    ///
+    /// Unlike the `Deref` implementation, the two targets do not need to be the same type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<String, Vec<u32>> = Left(String::from("text"));
+    /// assert_eq!(left.deref_either(), Left("text"));
+    ///
+    /// let right: Either<String, Vec<u32>> = Right(vec![1, 2]);
+    /// assert_eq!(right.deref_either(), Right(&[1, 2][..]));
+    /// ```
+    pub fn deref_either(&self) -> Either<&L::Target, &R::Target>
+        where L: Deref,
+              R: Deref
+    {
+        match *self {
+            Left(ref l) => Left(&**l),
+            Right(ref r) => Right(&**r),
+        }
+    }
+
+    /// Borrow the inner value as a byte slice.
+    ///
+    /// This is the same as the `AsRef<[u8]>` implementation, without needing a type
+    /// annotation for the target.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<Vec<u8>, &[u8]> = Left(vec![1, 2, 3]);
+    /// assert_eq!(left.as_bytes(), &[1, 2, 3]);
+    ///
+    /// let right: Either<Vec<u8>, &str> = Right("abc");
+    /// assert_eq!(right.as_bytes(), b"abc");
+    /// ```
+    pub fn as_bytes(&self) -> &[u8]
+        where L: AsRef<[u8]>,
+              R: AsRef<[u8]>
+    {
+        either!(*self, ref inner => inner.as_ref())
+    }
+
+    /// Write the bytes of the inner value to `out`.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut out = Vec::new();
+    /// let left: Either<Vec<u8>, &[u8]> = Left(vec![1, 2]);
+    /// let right: Either<Vec<u8>, &[u8]> = Right(&[3]);
+    /// left.write_to(&mut out).unwrap();
+    /// right.write_to(&mut out).unwrap();
+    /// assert_eq!(out, [1, 2, 3]);
+    /// ```
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn write_to<W>(&self, out: &mut W) -> io::Result<()>
+        where L: AsRef<[u8]>,
+              R: AsRef<[u8]>,
+              W: Write + ?Sized
+    {
+        out.write_all(self.as_bytes())
+    }
+
+    /// Write the inner value to `out` with its `Display` implementation.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut out = Vec::new();
+    /// let left: Either<u32, &str> = Left(123);
+    /// let right: Either<u32, &str> = Right(" apples");
+    /// left.write_display_to(&mut out).unwrap();
+    /// right.write_display_to(&mut out).unwrap();
+    /// assert_eq!(out, b"123 apples");
+    /// ```
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn write_display_to<W>(&self, out: &mut W) -> io::Result<()>
+        where L: fmt::Display,
+              R: fmt::Display,
+              W: Write + ?Sized
+    {
+        either!(*self, ref inner => write!(out, "{}", inner))
+    }
+
+    /// Return true if the inner value is a terminal, with `IsTerminal`.
+    ///
+    /// `IsTerminal` is sealed, so `Either` cannot implement it and forwards it with this
+    /// method instead.
+    ///
+    /// Requires crate feature `"is_terminal"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::io::{self, IsTerminal};
+    ///
+    /// let out: Either<io::Stdout, io::Stderr> = Left(io::stdout());
+    /// assert_eq!(out.is_terminal(), io::stdout().is_terminal());
+    /// ```
+    #[cfg(feature = "is_terminal")]
+    #[allow(clippy::incompatible_msrv)]
+    pub fn is_terminal(&self) -> bool
+        where L: IsTerminal,
+              R: IsTerminal
+    {
+        either!(*self, ref inner => inner.is_terminal())
+    }
+
+    /// Copy the value out of `&Either<L, R>` when both sides are `Copy`.
+    ///
+    /// This is the same as dereferencing `self`, but reads well at the end of a method chain.
+    /// For types that are only `Clone`, use `.clone()`, which may allocate.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: &Either<u32, f64> = &Left(3);
+    /// let doubled = value.as_copy().map_left(|x| x * 2);
+    /// let squared = value.as_copy().map_left(|x| x * x);
+    /// assert_eq!(doubled, Left(6));
+    /// assert_eq!(squared, Left(9));
+    /// ```
+    pub fn as_copy(&self) -> Either<L, R>
+        where L: Copy,
+              R: Copy
+    {
+        *self
+    }
+
+    /// Call the function `f` with a mutable borrow of the contents, as an `Either<&mut L, &mut R>`.
+    ///
+    /// This is convenient for calling a mutating method of whichever side is present.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut value: Either<Vec<u32>, VecDeque<u32>> = Left(vec![1, 2, 3, 4]);
+    /// value.with_inner_mut(|inner| match inner {
+    ///     Left(v) => v.retain(|&x| x % 2 == 0),
+    ///     Right(v) => v.retain(|&x| x % 2 == 0),
+    /// });
+    /// assert_eq!(value, Left(vec![2, 4]));
+    /// ```
+    pub fn with_inner_mut<F, T>(&mut self, f: F) -> T
+        where F: FnOnce(Either<&mut L, &mut R>) -> T
+    {
+        f(self.as_mut())
+    }
+
+    /// Call the function `f` with a mutable borrow of the value in the `Left` variant if it is
+    /// present. Return true if `f` was called.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<Vec<u32>, Vec<u32>> = Left(vec![]);
+    /// for i in 0..3 {
+    ///     assert!(value.with_left(|v| v.push(i)));
+    ///     assert!(!value.with_right(|v| v.push(i)));
+    /// }
+    /// assert_eq!(value, Left(vec![0, 1, 2]));
+    /// ```
+    pub fn with_left<F>(&mut self, f: F) -> bool
+        where F: FnOnce(&mut L)
+    {
+        match *self {
+            Left(ref mut l) => {
+                f(l);
+                true
+            }
+            Right(_) => false,
+        }
+    }
+
+    /// Call the function `f` with a mutable borrow of the value in the `Right` variant if it
+    /// is present. Return true if `f` was called.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<Vec<u32>, Vec<u32>> = Right(vec![]);
+    /// assert!(value.with_right(|v| v.push(1)));
+    /// assert!(!value.with_left(|v| v.push(2)));
+    /// assert_eq!(value, Right(vec![1]));
+    /// ```
+    pub fn with_right<F>(&mut self, f: F) -> bool
+        where F: FnOnce(&mut R)
+    {
+        match *self {
+            Left(_) => false,
+            Right(ref mut r) => {
+                f(r);
+                true
+            }
+        }
+    }
+
+    /// Replace the value with `Left(value)`, whatever its current variant, and return the
+    /// previous value.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<u32, &str> = Right("some value");
+    /// assert_eq!(value.replace_left(123), Right("some value"));
+    /// assert_eq!(value, Left(123));
+    /// ```
+    pub fn replace_left(&mut self, value: L) -> Either<L, R> {
+        mem::replace(self, Left(value))
+    }
+
+    /// Replace the value with `Right(value)`, whatever its current variant, and return the
+    /// previous value.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<u32, &str> = Left(123);
+    /// assert_eq!(value.replace_right("some value"), Left(123));
+    /// assert_eq!(value, Right("some value"));
+    /// ```
+    pub fn replace_right(&mut self, value: R) -> Either<L, R> {
+        mem::replace(self, Right(value))
+    }
+
+    /// Return an adapter that displays the `Left` value if it is present, and nothing
+    /// otherwise. Only `L` needs to implement `Display`.
+    ///
+    /// The text shown for a `Right` can be set with
+    /// [`LeftDisplay::placeholder`](struct.LeftDisplay.html#method.placeholder).
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// struct Opaque;
+    ///
+    /// let left: Either<u32, Opaque> = Left(123);
+    /// assert_eq!(format!("[{}]", left.left_display()), "[123]");
+    ///
+    /// let right: Either<u32, Opaque> = Right(Opaque);
+    /// assert_eq!(format!("[{}]", right.left_display()), "[]");
+    /// ```
+    pub fn left_display(&self) -> LeftDisplay<'_, L, R> {
+        LeftDisplay { inner: self, placeholder: "" }
+    }
+
+    /// Return an adapter that displays the `Right` value if it is present, and nothing
+    /// otherwise. Only `R` needs to implement `Display`.
+    ///
+    /// The text shown for a `Left` can be set with
+    /// [`RightDisplay::placeholder`](struct.RightDisplay.html#method.placeholder).
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// struct Opaque;
+    ///
+    /// let left: Either<Opaque, u32> = Left(Opaque);
+    /// assert_eq!(format!("[{}]", left.right_display()), "[]");
+    ///
+    /// let right: Either<Opaque, u32> = Right(123);
+    /// assert_eq!(format!("[{}]", right.right_display()), "[123]");
+    /// ```
+    pub fn right_display(&self) -> RightDisplay<'_, L, R> {
+        RightDisplay { inner: self, placeholder: "" }
+    }
+
+    /// Return an adapter that formats the value compactly as `L:<value>` or `R:<value>`.
+    ///
+    /// The adapter implements `Debug` if both sides implement `Debug`, and `Display` if both
+    /// sides implement `Display`. Formatting flags such as width and precision apply to the
+    /// inner value.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// assert_eq!(format!("{:?}", left.debug_tagged()), "L:123");
+    /// assert_eq!(format!("{:5}", left.debug_tagged()), "L:  123");
+    ///
+    /// let left: Either<f64, f64> = Left(0.5);
+    /// assert_eq!(format!("{:.2}", left.debug_tagged()), "L:0.50");
+    ///
+    /// let right: Either<u32, &str> = Right("text");
+    /// assert_eq!(format!("{:?}", right.debug_tagged()), "R:\"text\"");
+    /// assert_eq!(format!("{}", right.debug_tagged()), "R:text");
+    /// ```
+    pub fn debug_tagged(&self) -> DebugTagged<'_, L, R> {
+        DebugTagged { inner: self }
+    }
+
+    /// Convert `Either<L, R>` to `Either<R, L>`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left(123);
+    /// assert_eq!(left.flip(), Right(123));
+    ///
+    /// let right: Either<(), _> = Right("some value");
+    /// assert_eq!(right.flip(), Left("some value"));
+    /// ```
+    pub fn flip(self) -> Either<R, L> {
+        match self {
+            Left(l) => Right(l),
+            Right(r) => Left(r),
+        }
+    }
+
+    /// Create a `Right` value, following the `Ok => Right` mapping of the conversion from
+    /// `Result`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value = Either::<String, i32>::from_ok(123);
+    /// assert_eq!(value, Right(123));
+    /// ```
+    pub fn from_ok(r: R) -> Self {
+        Right(r)
+    }
+
+    /// Create a `Left` value, following the `Err => Left` mapping of the conversion from
+    /// `Result`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value = Either::<String, i32>::from_err(String::from("error"));
+    /// assert_eq!(value, Left(String::from("error")));
+    /// ```
+    pub fn from_err(l: L) -> Self {
+        Left(l)
+    }
+
+    /// Extract the `Either` from a `Result` holding one in both variants, discarding the
+    /// `Ok`/`Err` distinction.
+    ///
+    /// Only use this when it does not matter whether the value came from `Ok` or `Err`.
+    /// The same conversion is available as a method through
+    /// [`EitherResultExt::into_either`](trait.EitherResultExt.html#tymethod.into_either).
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let ok: Result<Either<u32, char>, Either<u32, char>> = Ok(Left(1));
+    /// assert_eq!(Either::flatten_result(ok), Left(1));
+    ///
+    /// let err: Result<Either<u32, char>, Either<u32, char>> = Err(Right('a'));
+    /// assert_eq!(Either::flatten_result(err), Right('a'));
+    /// ```
+    pub fn flatten_result(result: Result<Either<L, R>, Either<L, R>>) -> Self {
+        match result {
+            Ok(value) | Err(value) => value,
+        }
+    }
+
+    /// Collect an iterator of `Result`s into a `Left` value, stopping at the first error.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let lines = vec![Ok(1), Ok(2), Ok(3)];
+    /// let value = Either::<Vec<u32>, String>::from_results_left(lines);
+    /// assert_eq!(value, Ok::<_, &str>(Left(vec![1, 2, 3])));
+    ///
+    /// let lines = vec![Ok(1), Err("bad line"), Ok(3)];
+    /// let value = Either::<Vec<u32>, String>::from_results_left(lines);
+    /// assert_eq!(value, Err("bad line"));
+    /// ```
+    pub fn from_results_left<I, T, E>(iter: I) -> Result<Self, E>
+        where I: IntoIterator<Item = Result<T, E>>,
+              L: iter::FromIterator<T>
+    {
+        iter.into_iter().collect::<Result<L, E>>().map(Left)
+    }
+
+    /// Collect an iterator of `Result`s into a `Right` value, stopping at the first error.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let chars = vec![Ok('a'), Ok('b')];
+    /// let value = Either::<u32, String>::from_results_right(chars);
+    /// assert_eq!(value, Ok::<_, ()>(Right(String::from("ab"))));
+    /// ```
+    pub fn from_results_right<I, T, E>(iter: I) -> Result<Self, E>
+        where I: IntoIterator<Item = Result<T, E>>,
+              R: iter::FromIterator<T>
+    {
+        iter.into_iter().collect::<Result<R, E>>().map(Right)
+    }
+
+    /// Convert `Either<L, R>` to `Result<L, R>` with `Left => Ok` and `Right => Err`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, &str> = Left(123);
+    /// assert_eq!(left.into_ok_left(), Ok(123));
+    ///
+    /// let right: Either<u32, _> = Right("some value");
+    /// assert_eq!(right.into_ok_left(), Err("some value"));
+    /// ```
+    pub fn into_ok_left(self) -> Result<L, R> {
+        match self {
+            Left(l) => Ok(l),
+            Right(r) => Err(r),
+        }
+    }
+
+    /// Convert `Either<L, R>` to `Result<R, L>` with `Right => Ok` and `Left => Err`.
+    ///
+    /// This is the same conversion as the `From<Either<L, R>>` implementation of `Result`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, &str> = Left(123);
+    /// assert_eq!(left.into_ok_right(), Err(123));
+    ///
+    /// let right: Either<u32, _> = Right("some value");
+    /// assert_eq!(right.into_ok_right(), Ok("some value"));
+    /// ```
+    pub fn into_ok_right(self) -> Result<R, L> {
+        self.into()
+    }
+
+    /// Convert `&mut Either<L, R>` to `Result<&mut R, &mut L>`, with `Right => Ok` and
+    /// `Left => Err` like the conversion to `Result`.
+    ///
+    /// Mutating through the returned reference mutates `self` in place.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<&str, u32> = Right(123);
+    /// if let Ok(r) = value.as_result_mut() {
+    ///     *r += 1;
+    /// }
+    /// assert_eq!(value, Right(124));
+    /// ```
+    pub fn as_result_mut(&mut self) -> Result<&mut R, &mut L> {
+        match *self {
+            Left(ref mut l) => Err(l),
+            Right(ref mut r) => Ok(r),
+        }
+    }
+
+    /// Convert `&Either<L, R>` to `Result<&R, &L>`, with `Right => Ok` and `Left => Err`
+    /// like the conversion to `Result`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<&str, u32> = Right(123);
+    /// assert_eq!(right.as_result_ref(), Ok(&123));
+    ///
+    /// let left: Either<&str, u32> = Left("error");
+    /// assert_eq!(left.as_result_ref(), Err(&"error"));
+    /// ```
+    pub fn as_result_ref(&self) -> Result<&R, &L> {
+        match *self {
+            Left(ref l) => Err(l),
+            Right(ref r) => Ok(r),
+        }
+    }
+
+    /// Convert `&Either<L, R>` to `Result<&L, &R>`, with `Left => Ok` and `Right => Err`
+    /// like `into_ok_left`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// assert_eq!(left.as_result_left_ref(), Ok(&123));
+    ///
+    /// let right: Either<u32, &str> = Right("error");
+    /// assert_eq!(right.as_result_left_ref(), Err(&"error"));
+    /// ```
+    pub fn as_result_left_ref(&self) -> Result<&L, &R> {
+        match *self {
+            Left(ref l) => Ok(l),
+            Right(ref r) => Err(r),
+        }
+    }
+
+    /// Pair the value with the contents of `opt`, keeping the side.
+    ///
+    /// Return `None` if `opt` is `None`, whatever the side of `self`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// assert_eq!(left.zip_option(Some('a')), Some(Left((123, 'a'))));
+    ///
+    /// let right: Either<u32, &str> = Right("some value");
+    /// assert_eq!(right.zip_option(Some('a')), Some(Right(("some value", 'a'))));
+    /// assert_eq!(right.zip_option(None::<char>), None);
+    /// ```
+    pub fn zip_option<T>(self, opt: Option<T>) -> Option<Either<(L, T), (R, T)>> {
+        let t = opt?;
+        Some(match self {
+            Left(l) => Left((l, t)),
+            Right(r) => Right((r, t)),
+        })
+    }
+
+    /// Pair the value with the value of another `Either`, giving one variant of
+    /// [`EitherZip`](enum.EitherZip.html) for each combination of sides.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// let right: Either<char, bool> = Right(true);
+    /// assert_eq!(left.zip4(right), EitherZip::LeftRight(123, true));
+    ///
+    /// let right: Either<u32, &str> = Right("some value");
+    /// let left: Either<char, bool> = Left('a');
+    /// match right.zip4(left) {
+    ///     EitherZip::LeftLeft(..) | EitherZip::LeftRight(..) => unreachable!(),
+    ///     EitherZip::RightLeft(s, c) => assert_eq!((s, c), ("some value", 'a')),
+    ///     EitherZip::RightRight(..) => unreachable!(),
+    /// }
+    /// ```
+    pub fn zip4<LB, RB>(self, other: Either<LB, RB>) -> EitherZip<L, R, LB, RB> {
+        match (self, other) {
+            (Left(a), Left(b)) => EitherZip::LeftLeft(a, b),
+            (Left(a), Right(b)) => EitherZip::LeftRight(a, b),
+            (Right(a), Left(b)) => EitherZip::RightLeft(a, b),
+            (Right(a), Right(b)) => EitherZip::RightRight(a, b),
+        }
+    }
+
+    /// Return `Err(e)` if `err` is `Some(e)`, otherwise `Ok(self)`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: Either<u32, &str> = Left(123);
+    /// assert_eq!(value.ok_or_opt(None::<()>), Ok(Left(123)));
+    /// assert_eq!(value.ok_or_opt(Some("invalid")), Err("invalid"));
+    /// ```
+    pub fn ok_or_opt<E>(self, err: Option<E>) -> Result<Either<L, R>, E> {
+        match err {
+            Some(e) => Err(e),
+            None => Ok(self),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Left` variant if it is present rewrapping the
+    /// result in `Left`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.map_left(|x| x * 2), Left(246));
+    ///
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.map_left(|x| x * 2), Right(123));
+    /// ```
+    pub fn map_left<F, M>(self, f: F) -> Either<M, R>
+        where F: FnOnce(L) -> M
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(r),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Right` variant if it is present rewrapping the
+    /// result in `Right`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.map_right(|x| x * 2), Left(123));
+    ///
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.map_right(|x| x * 2), Right(246));
+    /// ```
+    pub fn map_right<F, S>(self, f: F) -> Either<L, S>
+        where F: FnOnce(R) -> S
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => Right(f(r)),
+        }
+    }
+
+    /// Convert the value in the `Left` variant with `From` if it is present, like the early
+    /// return of `try_right!`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u8, &str> = Left(123);
+    /// assert_eq!(left.map_left_into::<u32>(), Left(123u32));
+    /// ```
+    pub fn map_left_into<M>(self) -> Either<M, R>
+        where M: From<L>
+    {
+        self.map_left(M::from)
+    }
+
+    /// Convert the value in the `Right` variant with `From` if it is present, like the early
+    /// return of `try_left!`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<u32, &str> = Right("error");
+    /// assert_eq!(right.map_right_into::<String>(), Right(String::from("error")));
+    /// ```
+    pub fn map_right_into<S>(self) -> Either<L, S>
+        where S: From<R>
+    {
+        self.map_right(S::from)
+    }
+
+    /// Apply the asynchronous function `f` on the value in the `Left` variant if it is
+    /// present, returning a future that resolves to the result rewrapped in `Left`.
+    ///
+    /// A `Right` value is passed through, and the future resolves to it on the first poll.
+    /// The future returned by `f` is stored inline, so the result is `Unpin` only if that
+    /// future and the `Right` value are.
+    ///
+    /// Requires crate feature `"future"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    /// # // `Waker::noop` and `future::ready` need newer Rust than the crate supports.
+    /// # fn noop_waker() -> Waker {
+    /// #     use std::task::{RawWaker, RawWakerVTable};
+    /// #     fn clone(_: *const ()) -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+    /// #     fn noop(_: *const ()) {}
+    /// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    /// #     unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    /// # }
+    /// # struct Ready<T>(Option<T>);
+    /// # impl<T: Unpin> Future for Ready<T> {
+    /// #     type Output = T;
+    /// #     fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<T> {
+    /// #         Poll::Ready(self.0.take().unwrap())
+    /// #     }
+    /// # }
+    /// # fn ready<T>(value: T) -> Ready<T> { Ready(Some(value)) }
+    ///
+    /// let waker = noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// let mut left = Left::<u32, &str>(123).map_left_fut(|x| ready(x * 2));
+    /// assert_eq!(Pin::new(&mut left).poll(&mut cx), Poll::Ready(Left(246)));
+    ///
+    /// let mut right = Right::<u32, &str>("text").map_left_fut(|x| ready(x * 2));
+    /// assert_eq!(Pin::new(&mut right).poll(&mut cx), Poll::Ready(Right("text")));
+    /// ```
+    #[cfg(feature = "future")]
+    pub fn map_left_fut<F, Fut>(self, f: F) -> MapLeftFuture<Fut, R>
+        where F: FnOnce(L) -> Fut,
+              Fut: Future
+    {
+        MapLeftFuture {
+            inner: match self {
+                Left(l) => Left(f(l)),
+                Right(r) => Right(Some(r)),
+            },
+        }
+    }
+
+    /// Apply the asynchronous function `f` on the value in the `Right` variant if it is
+    /// present, returning a future that resolves to the result rewrapped in `Right`.
+    ///
+    /// A `Left` value is passed through, and the future resolves to it on the first poll.
+    /// The future returned by `f` is stored inline, so the result is `Unpin` only if that
+    /// future and the `Left` value are.
+    ///
+    /// Requires crate feature `"future"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    /// # // `Waker::noop` and `future::ready` need newer Rust than the crate supports.
+    /// # fn noop_waker() -> Waker {
+    /// #     use std::task::{RawWaker, RawWakerVTable};
+    /// #     fn clone(_: *const ()) -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+    /// #     fn noop(_: *const ()) {}
+    /// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    /// #     unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    /// # }
+    /// # struct Ready<T>(Option<T>);
+    /// # impl<T: Unpin> Future for Ready<T> {
+    /// #     type Output = T;
+    /// #     fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<T> {
+    /// #         Poll::Ready(self.0.take().unwrap())
+    /// #     }
+    /// # }
+    /// # fn ready<T>(value: T) -> Ready<T> { Ready(Some(value)) }
+    ///
+    /// let waker = noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// let mut right = Right::<&str, u32>(123).map_right_fut(|x| ready(x * 2));
+    /// assert_eq!(Pin::new(&mut right).poll(&mut cx), Poll::Ready(Right(246)));
+    ///
+    /// let mut left = Left::<&str, u32>("text").map_right_fut(|x| ready(x * 2));
+    /// assert_eq!(Pin::new(&mut left).poll(&mut cx), Poll::Ready(Left("text")));
+    /// ```
+    #[cfg(feature = "future")]
+    pub fn map_right_fut<F, Fut>(self, f: F) -> MapRightFuture<L, Fut>
+        where F: FnOnce(R) -> Fut,
+              Fut: Future
+    {
+        MapRightFuture {
+            inner: match self {
+                Left(l) => Left(Some(l)),
+                Right(r) => Right(f(r)),
+            },
+        }
+    }
+
+    /// Return a future that awaits whichever future is present and resolves to its output,
+    /// rewrapped in the same variant.
+    ///
+    /// The two futures can have different output types. The present future is stored inline,
+    /// so the result is `Unpin` only if both future types are.
+    ///
+    /// Requires crate feature `"future"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    /// # // `Waker::noop` and `future::ready` need newer Rust than the crate supports.
+    /// # fn noop_waker() -> Waker {
+    /// #     use std::task::{RawWaker, RawWakerVTable};
+    /// #     fn clone(_: *const ()) -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+    /// #     fn noop(_: *const ()) {}
+    /// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    /// #     unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    /// # }
+    /// # struct Ready<T>(Option<T>);
+    /// # impl<T: Unpin> Future for Ready<T> {
+    /// #     type Output = T;
+    /// #     fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<T> {
+    /// #         Poll::Ready(self.0.take().unwrap())
+    /// #     }
+    /// # }
+    /// # fn ready<T>(value: T) -> Ready<T> { Ready(Some(value)) }
+    ///
+    /// let waker = noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// let task: Either<Ready<u32>, Ready<&str>> = Left(ready(123));
+    /// let mut output = task.await_either();
+    /// assert_eq!(Pin::new(&mut output).poll(&mut cx), Poll::Ready(Left(123)));
+    /// ```
+    #[cfg(feature = "future")]
+    pub fn await_either(self) -> AwaitEither<L, R>
+        where L: Future,
+              R: Future
+    {
+        AwaitEither { inner: self }
+    }
+
+    /// Apply one of two functions depending on contents, rewrapping the result in the same
+    /// variant. If the value is `Left(L)` then the first function `f` is applied; if it is
+    /// `Right(R)` then the second function `g` is applied.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// assert_eq!(left.map_either(|x| x * 2, |s| s.len()), Left(246));
+    ///
+    /// let right: Either<u32, &str> = Right("some value");
+    /// assert_eq!(right.map_either(|x| x * 2, |s| s.len()), Right(10));
+    /// ```
+    pub fn map_either<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
+        where F: FnOnce(L) -> M,
+              G: FnOnce(R) -> S
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(g(r)),
+        }
+    }
+
+    /// Apply one of two functions depending on contents, wrapping the result in the opposite
+    /// variant. This is `map_either` followed by `flip`.
+    ///
+    /// A `Left(L)` becomes `Right(f(L))` and a `Right(R)` becomes `Left(g(R))`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// assert_eq!(left.relabel(|x| x.to_string(), |s| s.len()), Right(String::from("123")));
+    ///
+    /// let right: Either<u32, &str> = Right("four");
+    /// assert_eq!(right.relabel(|x| x.to_string(), |s| s.len()), Left(4));
+    /// ```
+    pub fn relabel<F, G, M, S>(self, f: F, g: G) -> Either<S, M>
+        where F: FnOnce(L) -> M,
+              G: FnOnce(R) -> S
+    {
+        match self {
+            Left(l) => Right(f(l)),
+            Right(r) => Left(g(r)),
+        }
+    }
+
+    /// Return a function that applies `f` with [`map_left`](#method.map_left) to each `Either`
+    /// it is called with, for use with `Iterator::map` and similar.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values: Vec<Either<u32, &str>> = vec![Left(1), Right("a"), Left(2)];
+    /// let doubled: Vec<_> = values.into_iter().map(Either::map_left_fn(|x| x * 2)).collect();
+    /// assert_eq!(doubled, vec![Left(2), Right("a"), Left(4)]);
+    /// ```
+    pub fn map_left_fn<F, M>(mut f: F) -> impl FnMut(Either<L, R>) -> Either<M, R>
+        where F: FnMut(L) -> M
+    {
+        move |either| either.map_left(&mut f)
+    }
+
+    /// Return a function that applies `f` with [`map_right`](#method.map_right) to each
+    /// `Either` it is called with, for use with `Iterator::map` and similar.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values: Vec<Either<&str, u32>> = vec![Right(1), Left("a"), Right(2)];
+    /// let doubled: Vec<_> = values.into_iter().map(Either::map_right_fn(|x| x * 2)).collect();
+    /// assert_eq!(doubled, vec![Right(2), Left("a"), Right(4)]);
+    /// ```
+    pub fn map_right_fn<F, S>(mut f: F) -> impl FnMut(Either<L, R>) -> Either<L, S>
+        where F: FnMut(R) -> S
+    {
+        move |either| either.map_right(&mut f)
+    }
+
+    /// Return a function that applies `f` and `g` with [`map_either`](#method.map_either) to
+    /// each `Either` it is called with, for use with `Iterator::map` and similar.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let values: Vec<Either<u32, &str>> = vec![Left(1), Right("abc")];
+    /// let mapped: Vec<_> = values.into_iter()
+    ///                            .map(Either::map_either_fn(|x| x * 2, |s: &str| s.len()))
+    ///                            .collect();
+    /// assert_eq!(mapped, vec![Left(2), Right(3)]);
+    /// ```
+    pub fn map_either_fn<F, G, M, S>(mut f: F, mut g: G) -> impl FnMut(Either<L, R>) -> Either<M, S>
+        where F: FnMut(L) -> M,
+              G: FnMut(R) -> S
+    {
+        move |either| either.map_either(&mut f, &mut g)
+    }
+
+    /// Alias of [`map_either`](#method.map_either), using the name of the bifunctor map
+    /// found in other languages.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// assert_eq!(left.bimap(|x| x * 2, |s| s.len()), Left(246));
+    /// ```
+    pub fn bimap<F, G, M, S>(self, f: F, g: G) -> Either<M, S>
+        where F: FnOnce(L) -> M,
+              G: FnOnce(R) -> S
+    {
+        self.map_either(f, g)
+    }
+
+    /// Alias of [`map_left`](#method.map_left), using the bifunctor naming found in other
+    /// languages.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.first(|x| x * 2), Left(246));
+    /// ```
+    pub fn first<F, M>(self, f: F) -> Either<M, R>
+        where F: FnOnce(L) -> M
+    {
+        self.map_left(f)
+    }
+
+    /// Alias of [`map_right`](#method.map_right), using the bifunctor naming found in other
+    /// languages.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.second(|x| x * 2), Right(246));
+    /// ```
+    pub fn second<F, S>(self, f: F) -> Either<L, S>
+        where F: FnOnce(R) -> S
+    {
+        self.map_right(f)
+    }
+
+    /// Alias of [`map_left`](#method.map_left), named after `Result::map_err` for code that
+    /// uses `Left` as the error side, like the conversion to `Result`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u32> = Left("error");
+    /// assert_eq!(left.map_err(|e| e.len()), Left(5));
+    /// ```
+    pub fn map_err<F, M>(self, f: F) -> Either<M, R>
+        where F: FnOnce(L) -> M
+    {
+        self.map_left(f)
+    }
+
+    /// Alias of [`map_right`](#method.map_right), named after `Result::map` for code that
+    /// uses `Right` as the success side, like the conversion to `Result`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<&str, u32> = Right(123);
+    /// assert_eq!(right.map_ok(|x| x * 2), Right(246));
+    /// ```
+    pub fn map_ok<F, S>(self, f: F) -> Either<L, S>
+        where F: FnOnce(R) -> S
+    {
+        self.map_right(f)
+    }
+
+    /// Like [`map_either`](#method.map_either), but apply the functions on a borrow of the
+    /// contents, leaving `self` untouched.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<String, Vec<u32>> = Left(String::from("hello"));
+    /// assert_eq!(left.map_either_ref(|s| s.len(), |v| v.is_empty()), Left(5));
+    ///
+    /// let right: Either<String, Vec<u32>> = Right(vec![]);
+    /// assert_eq!(right.map_either_ref(|s| s.len(), |v| v.is_empty()), Right(true));
+    ///
+    /// // the value is still available
+    /// assert_eq!(right, Right(vec![]));
+    /// ```
+    pub fn map_either_ref<F, G, M, S>(&self, f: F, g: G) -> Either<M, S>
+        where F: FnOnce(&L) -> M,
+              G: FnOnce(&R) -> S
+    {
+        match *self {
+            Left(ref l) => Left(f(l)),
+            Right(ref r) => Right(g(r)),
+        }
+    }
+
+    /// Like [`map_either`](#method.map_either), but with fallible functions. Only the function
+    /// for the present variant is called, and its error is returned.
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::num::ParseIntError;
+    ///
+    /// fn parse(value: Either<&str, &str>) -> Result<Either<i32, u8>, ParseIntError> {
+    ///     value.try_map_either(|s| s.parse::<i32>(), |s| s.parse::<u8>())
+    /// }
+    ///
+    /// assert_eq!(parse(Left("-1")), Ok(Left(-1)));
+    /// assert_eq!(parse(Right("255")), Ok(Right(255)));
+    /// assert!(parse(Left("x")).is_err());
+    /// assert!(parse(Right("256")).is_err());
+    /// ```
+    pub fn try_map_either<F, G, M, S, E>(self, f: F, g: G) -> Result<Either<M, S>, E>
+        where F: FnOnce(L) -> Result<M, E>,
+              G: FnOnce(R) -> Result<S, E>
+    {
+        match self {
+            Left(l) => f(l).map(Left),
+            Right(r) => g(r).map(Right),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Left` variant if it is present, otherwise
+    /// return the default value of the result type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, &str> = Left(123);
+    /// assert_eq!(left.left_map_or_default(|x| x * 2), 246);
+    ///
+    /// let right: Either<u32, _> = Right("some value");
+    /// assert_eq!(right.left_map_or_default(|x| x * 2), 0);
+    /// ```
+    pub fn left_map_or_default<F, U>(self, f: F) -> U
+        where F: FnOnce(L) -> U,
+              U: Default
+    {
+        match self {
+            Left(l) => f(l),
+            Right(_) => U::default(),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Right` variant if it is present, otherwise
+    /// return the default value of the result type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<&str, u32> = Left("some value");
+    /// assert_eq!(left.right_map_or_default(|x| x * 2), 0);
+    ///
+    /// let right: Either<&str, _> = Right(123);
+    /// assert_eq!(right.right_map_or_default(|x| x * 2), 246);
+    /// ```
+    pub fn right_map_or_default<F, U>(self, f: F) -> U
+        where F: FnOnce(R) -> U,
+              U: Default
+    {
+        match self {
+            Left(_) => U::default(),
+            Right(r) => f(r),
+        }
+    }
+
+    /// Call `on_left` with a reference to the value in the `Left` variant, or `on_right` with a
+    /// reference to the value in the `Right` variant, and return `self` unchanged.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut lefts = 0;
+    /// let mut rights = 0;
+    /// let value: Either<u32, &str> = Left(123);
+    /// let value = value.inspect_both(|_| lefts += 1, |_| rights += 1);
+    /// assert_eq!(value, Left(123));
+    /// assert_eq!((lefts, rights), (1, 0));
+    /// ```
+    pub fn inspect_both<F, G>(self, on_left: F, on_right: G) -> Self
+        where F: FnOnce(&L),
+              G: FnOnce(&R)
+    {
+        match self {
+            Left(ref l) => on_left(l),
+            Right(ref r) => on_right(r),
+        }
+        self
+    }
+
+    /// Run the fallible check `f` on the value in the `Left` variant if it is present, and
+    /// return `self` unchanged if it succeeds.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn non_zero(x: &u32) -> Result<(), &'static str> {
+    ///     if *x != 0 { Ok(()) } else { Err("zero") }
+    /// }
+    ///
+    /// let left: Either<u32, u32> = Left(123);
+    /// assert_eq!(left.try_inspect_left(non_zero), Ok(Left(123)));
+    ///
+    /// let left: Either<u32, u32> = Left(0);
+    /// assert_eq!(left.try_inspect_left(non_zero), Err("zero"));
+    ///
+    /// let right: Either<u32, u32> = Right(0);
+    /// assert_eq!(right.try_inspect_left(non_zero), Ok(Right(0)));
+    /// ```
+    pub fn try_inspect_left<F, E>(self, f: F) -> Result<Self, E>
+        where F: FnOnce(&L) -> Result<(), E>
+    {
+        if let Left(ref l) = self {
+            f(l)?;
+        }
+        Ok(self)
+    }
+
+    /// Run the fallible check `f` on the value in the `Right` variant if it is present, and
+    /// return `self` unchanged if it succeeds.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn non_zero(x: &u32) -> Result<(), &'static str> {
+    ///     if *x != 0 { Ok(()) } else { Err("zero") }
+    /// }
+    ///
+    /// let right: Either<u32, u32> = Right(123);
+    /// assert_eq!(right.try_inspect_right(non_zero), Ok(Right(123)));
+    ///
+    /// let right: Either<u32, u32> = Right(0);
+    /// assert_eq!(right.try_inspect_right(non_zero), Err("zero"));
+    ///
+    /// let left: Either<u32, u32> = Left(0);
+    /// assert_eq!(left.try_inspect_right(non_zero), Ok(Left(0)));
+    /// ```
+    pub fn try_inspect_right<F, E>(self, f: F) -> Result<Self, E>
+        where F: FnOnce(&R) -> Result<(), E>
+    {
+        if let Right(ref r) = self {
+            f(r)?;
+        }
+        Ok(self)
+    }
+
+    /// Apply one of two functions depending on contents, unifying their result. If the value is
+    /// `Left(L)` then the first function `f` is applied; if it is `Right(R)` then the second
+    /// function `g` is applied.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn square(n: u32) -> i32 { (n * n) as i32 }
+    /// fn negate(n: i32) -> i32 { -n }
+    ///
+    /// let left: Either<u32, i32> = Left(4);
+    /// assert_eq!(left.either(square, negate), 16);
+    ///
+    /// let right: Either<u32, i32> = Right(-4);
+    /// assert_eq!(right.either(square, negate), 4);
+    /// ```
+    pub fn either<F, G, T>(self, f: F, g: G) -> T
+      where F: FnOnce(L) -> T,
+            G: FnOnce(R) -> T
+    {
+        match self {
+            Left(l) => f(l),
+            Right(r) => g(r),
+        }
+    }
+
+    /// Like `either`, but provide some context to whichever of the
+    /// functions ends up being called.
+    ///
+    /// ```
+    /// // In this example, the context is a mutable reference
+    /// use either::*;
+    ///
+    /// let mut result = Vec::new();
+    ///
+    /// let values = vec![Left(2), Right(2.7)];
+    ///
+    /// for value in values {
+    ///     value.either_with(&mut result,
+    ///                       |ctx, integer| ctx.push(integer),
+    ///                       |ctx, real| ctx.push(f64::round(real) as i32));
+    /// }
+    ///
+    /// assert_eq!(result, vec![2, 3]);
+    /// ```
+    pub fn either_with<Ctx, F, G, T>(self, ctx: Ctx, f: F, g: G) -> T
+      where F: FnOnce(Ctx, L) -> T,
+            G: FnOnce(Ctx, R) -> T
+    {
+        match self {
+            Left(l) => f(ctx, l),
+            Right(r) => g(ctx, r),
+        }
+    }
+
+    /// Like `either_with`, but take the context explicitly as a mutable reference, so the
+    /// result can borrow from it.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut names = vec![String::from("a")];
+    ///
+    /// let value: Either<usize, String> = Right(String::from("b"));
+    /// let name = value.either_with_mut(&mut names,
+    ///                                  |names, index| &names[index],
+    ///                                  |names, name| { names.push(name); &names[names.len() - 1] });
+    /// assert_eq!(name, "b");
+    /// ```
+    pub fn either_with_mut<'c, C, F, G, T>(self, ctx: &'c mut C, f: F, g: G) -> T
+      where C: ?Sized,
+            F: FnOnce(&'c mut C, L) -> T,
+            G: FnOnce(&'c mut C, R) -> T
+    {
+        match self {
+            Left(l) => f(ctx, l),
+            Right(r) => g(ctx, r),
+        }
+    }
+
+    /// Like `either`, but apply the function to a mutable reference to the value in place.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<Vec<u32>, String> = Left(vec![1, 2]);
+    /// let len = value.either_mut(|v| { v.push(3); v.len() },
+    ///                            |s| { s.push('!'); s.len() });
+    /// assert_eq!(len, 3);
+    /// assert_eq!(value, Left(vec![1, 2, 3]));
+    /// ```
+    pub fn either_mut<F, G, T>(&mut self, f: F, g: G) -> T
+      where F: FnOnce(&mut L) -> T,
+            G: FnOnce(&mut R) -> T
+    {
+        match *self {
+            Left(ref mut l) => f(l),
+            Right(ref mut r) => g(r),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Left` variant if it is present.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.left_and_then::<_,()>(|x| Right(x * 2)), Right(246));
+    ///
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.left_and_then(|x| Right::<(), _>(x * 2)), Right(123));
+    /// ```
+    pub fn left_and_then<F, S>(self, f: F) -> Either<S, R>
+        where F: FnOnce(L) -> Either<S, R>
+    {
+        match self {
+            Left(l) => f(l),
+            Right(r) => Right(r),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Right` variant if it is present.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.right_and_then(|x| Right(x * 2)), Left(123));
+    ///
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.right_and_then(|x| Right(x * 2)), Right(246));
+    /// ```
+    pub fn right_and_then<F, S>(self, f: F) -> Either<L, S>
+        where F: FnOnce(R) -> Either<L, S>
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => f(r),
+        }
+    }
+
+    /// Like `left_and_then`, but provide some context to the function if it is called.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let left: Either<u32, &str> = Left(123);
+    /// let value = left.left_and_then_with(&mut log, |log, x| { log.push(x); Left::<_, &str>(x * 2) });
+    /// assert_eq!(value, Left(246));
+    ///
+    /// let right: Either<u32, &str> = Right("text");
+    /// let value = right.left_and_then_with(&mut log, |log, x| { log.push(x); Left::<_, &str>(x * 2) });
+    /// assert_eq!(value, Right("text"));
+    /// assert_eq!(log, vec![123]);
+    /// ```
+    pub fn left_and_then_with<Ctx, F, S>(self, ctx: Ctx, f: F) -> Either<S, R>
+        where F: FnOnce(Ctx, L) -> Either<S, R>
+    {
+        match self {
+            Left(l) => f(ctx, l),
+            Right(r) => Right(r),
+        }
+    }
+
+    /// Like `right_and_then`, but provide some context to the function if it is called.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut seen = Vec::new();
+    /// let values: Vec<Either<&str, u32>> = vec![Right(1), Left("skip"), Right(3)];
+    /// for value in values {
+    ///     let checked = value.right_and_then_with(&mut seen, |seen, x| {
+    ///         seen.push(x);
+    ///         if x > 2 { Left("too big") } else { Right(x) }
+    ///     });
+    ///     assert!(checked != Right(3));
+    /// }
+    /// assert_eq!(seen, vec![1, 3]);
+    /// ```
+    pub fn right_and_then_with<Ctx, F, S>(self, ctx: Ctx, f: F) -> Either<L, S>
+        where F: FnOnce(Ctx, R) -> Either<L, S>
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => f(ctx, r),
+        }
+    }
+
+    /// Apply the function `f` on the value in the `Right` variant if it is present, treating
+    /// `Right` as success and `Left` as an error that short-circuits, like `Result::and_then`.
+    ///
+    /// This is the same as `right_and_then`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn half(x: u32) -> Either<&'static str, u32> {
+    ///     if x % 2 == 0 { Right(x / 2) } else { Left("odd") }
+    /// }
+    ///
+    /// assert_eq!(Right(8).and_then(half).and_then(half), Right(2));
+    /// assert_eq!(Right(6).and_then(half).and_then(half), Left("odd"));
+    /// assert_eq!(Left("error").and_then(half), Left("error"));
+    /// ```
+    pub fn and_then<F, S>(self, f: F) -> Either<L, S>
+        where F: FnOnce(R) -> Either<L, S>
+    {
+        self.right_and_then(f)
+    }
+
+    /// Apply the function `f` on the value in the `Left` variant if it is present, treating
+    /// `Left` as an error to recover from, like `Result::or_else`.
+    ///
+    /// This is the same as `left_and_then`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn retry(attempts: u32) -> Either<u32, &'static str> {
+    ///     if attempts < 3 { Left(attempts + 1) } else { Right("done") }
+    /// }
+    ///
+    /// assert_eq!(Left(1).or_else(retry), Left(2));
+    /// assert_eq!(Left(3).or_else(retry), Right("done"));
+    /// assert_eq!(Right::<u32, _>("ok").or_else(retry), Right("ok"));
+    /// ```
+    pub fn or_else<F, M>(self, f: F) -> Either<M, R>
+        where F: FnOnce(L) -> Either<M, R>
+    {
+        self.left_and_then(f)
+    }
+
+    /// Return `self` if it is a `Left`, otherwise `other`.
+    ///
+    /// A `Left` in `self` takes precedence over anything in `other`; when `self` is a
+    /// `Right` it is discarded, even if `other` is a `Right` too.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(1);
+    /// let right: Either<u32, &str> = Right("a");
+    /// assert_eq!(left.coalesce_left(Left(2)), Left(1));
+    /// assert_eq!(right.coalesce_left(Left(2)), Left(2));
+    /// assert_eq!(right.coalesce_left(Right("b")), Right("b"));
+    /// ```
+    pub fn coalesce_left(self, other: Either<L, R>) -> Either<L, R> {
+        match self {
+            Left(l) => Left(l),
+            Right(_) => other,
+        }
+    }
+
+    /// Return `self` if it is a `Right`, otherwise `other`.
+    ///
+    /// A `Right` in `self` takes precedence over anything in `other`; when `self` is a
+    /// `Left` it is discarded, even if `other` is a `Left` too.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u32, &str> = Left(1);
+    /// let right: Either<u32, &str> = Right("a");
+    /// assert_eq!(right.coalesce_right(Right("b")), Right("a"));
+    /// assert_eq!(left.coalesce_right(Right("b")), Right("b"));
+    /// assert_eq!(left.coalesce_right(Left(2)), Left(2));
+    /// ```
+    pub fn coalesce_right(self, other: Either<L, R>) -> Either<L, R> {
+        match self {
+            Left(_) => other,
+            Right(r) => Right(r),
+        }
+    }
+
+    /// Return the value in the `Left` variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message `msg` followed by the `Right` value if the value is a `Right`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left(123);
+    /// assert_eq!(left.expect_left("value was Right"), 123);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use either::*;
+    ///
+    /// let right: Either<(), _> = Right(123);
+    /// right.expect_left("value was Right"); // panics with `value was Right: 123`
+    /// ```
+    #[track_caller]
+    pub fn expect_left(self, msg: &str) -> L
+        where R: fmt::Debug
+    {
+        match self {
+            Left(l) => l,
+            Right(r) => panic!("{}: {:?}", msg, r),
+        }
+    }
+
+    /// Return the value in the `Right` variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message `msg` followed by the `Left` value if the value is a `Left`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<(), _> = Right(123);
+    /// assert_eq!(right.expect_right("value was Left"), 123);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use either::*;
+    ///
+    /// let left: Either<_, ()> = Left(123);
+    /// left.expect_right("value was Left"); // panics with `value was Left: 123`
+    /// ```
+    #[track_caller]
+    pub fn expect_right(self, msg: &str) -> R
+        where L: fmt::Debug
+    {
+        match self {
+            Left(l) => panic!("{}: {:?}", msg, l),
+            Right(r) => r,
+        }
+    }
+
+    /// Convert the inner value of either side to a common type `T` with `Into`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<u8, u16> = Left(1);
+    /// assert_eq!(left.into_common::<u32>(), 1);
+    ///
+    /// let right: Either<&str, String> = Right(String::from("owned"));
+    /// assert_eq!(right.into_common::<String>(), "owned");
+    /// ```
+    #[inline]
+    pub fn into_common<T>(self) -> T
+        where L: Into<T>,
+              R: Into<T>
+    {
+        either!(self, inner => inner.into())
+    }
+
+    /// Return the value in the `Left` variant, or convert the value in the `Right` variant
+    /// to `L` with `Into`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let configured: Either<String, &str> = Left(String::from("custom"));
+    /// assert_eq!(configured.unwrap_left_or_clone(), "custom");
+    ///
+    /// let fallback: Either<String, &str> = Right("default");
+    /// assert_eq!(fallback.unwrap_left_or_clone(), "default");
+    /// ```
+    pub fn unwrap_left_or_clone(self) -> L
+        where L: Clone,
+              R: Into<L>
+    {
+        match self {
+            Left(l) => l,
+            Right(r) => r.into(),
+        }
+    }
+
+    /// Convert the inner value of either side to a common type `T` with `TryInto`.
+    ///
+    /// If the conversion fails, the error is returned on the side it came from.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<i64, u64> = Left(-1);
+    /// assert_eq!(left.try_into_inner::<i32>(), Ok(-1));
+    ///
+    /// let right: Either<i64, u64> = Right(u64::MAX);
+    /// assert!(right.try_into_inner::<i32>().unwrap_err().is_right());
+    /// ```
+    pub fn try_into_inner<T>(self) -> Result<T, Either<L::Error, R::Error>>
+        where L: TryInto<T>,
+              R: TryInto<T>
+    {
+        match self {
+            Left(l) => l.try_into().map_err(Left),
+            Right(r) => r.try_into().map_err(Right),
+        }
+    }
+
+    /// Alias of [`try_into_inner`](#method.try_into_inner), named as the fallible
+    /// counterpart of [`into_common`](#method.into_common).
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<i64, u64> = Right(7);
+    /// assert_eq!(right.coerce::<u8>(), Ok(7));
+    ///
+    /// let left: Either<i64, u64> = Left(-1);
+    /// assert!(left.coerce::<u8>().unwrap_err().is_left());
+    /// ```
+    pub fn coerce<T>(self) -> Result<T, Either<L::Error, R::Error>>
+        where L: TryInto<T>,
+              R: TryInto<T>
+    {
+        self.try_into_inner()
+    }
+
+    /// Convert the inner value to an iterator.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, Vec<u32>> = Left(vec![1, 2, 3, 4, 5]);
+    /// let mut right: Either<Vec<u32>, _> = Right(vec![]);
+    /// right.extend(left.into_iter());
+    /// assert_eq!(right, Right(vec![1, 2, 3, 4, 5]));
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> Either<L::IntoIter, R::IntoIter>
+        where L: IntoIterator,
+              R: IntoIterator<Item = L::Item>
+    {
+        match self {
+            Left(l) => Left(l.into_iter()),
+            Right(r) => Right(r.into_iter()),
+        }
+    }
+
+    /// Box the inner iterator as a [`DynEither`](iterator/struct.DynEither.html), so that it
+    /// can be unified with other iterator types.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter: Either<_, std::vec::IntoIter<u32>> = Left(1..4);
+    /// assert_eq!(iter.dynamic().sum::<u32>(), 6);
+    /// ```
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn dynamic<'a>(self) -> iterator::DynEither<'a, L::Item>
+        where L: Iterator + 'a,
+              R: Iterator<Item = L::Item> + 'a
+    {
+        iterator::DynEither::new(self)
+    }
+
+    /// Box the inner double-ended iterator as a
+    /// [`DynEitherDoubleEnded`](iterator/struct.DynEitherDoubleEnded.html), so that it can be
+    /// unified with other double-ended iterator types.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter: Either<_, std::vec::IntoIter<u32>> = Left(1..4);
+    /// assert_eq!(iter.dynamic_double_ended().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    #[cfg(any(test, feature = "use_std"))]
+    pub fn dynamic_double_ended<'a>(self) -> iterator::DynEitherDoubleEnded<'a, L::Item>
+        where L: DoubleEndedIterator + 'a,
+              R: DoubleEndedIterator<Item = L::Item> + 'a
+    {
+        iterator::DynEitherDoubleEnded::new(self)
+    }
+
+    /// Repeat the inner iterator endlessly with `Iterator::cycle`, keeping the `Either`.
+    ///
+    /// Unlike `Iterator::cycle` on the `Either` itself, which returns `Cycle<Either<L, R>>`,
+    /// this cycles each side separately and returns `Either<Cycle<L>, Cycle<R>>`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter: Either<_, std::vec::IntoIter<u32>> = Left(1..3);
+    /// assert_eq!(iter.cycle_either().take(5).collect::<Vec<_>>(), vec![1, 2, 1, 2, 1]);
+    /// ```
+    pub fn cycle_either(self) -> Either<iter::Cycle<L>, iter::Cycle<R>>
+        where L: Iterator + Clone,
+              R: Iterator + Clone
+    {
+        match self {
+            Left(l) => Left(l.cycle()),
+            Right(r) => Right(r.cycle()),
+        }
+    }
+
+    /// Fuse the inner iterator with `Iterator::fuse`, keeping the `Either`.
+    ///
+    /// Unlike `Iterator::fuse` on the `Either` itself, which returns `Fuse<Either<L, R>>`,
+    /// this fuses each side separately and returns `Either<Fuse<L>, Fuse<R>>`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter: Either<_, std::vec::IntoIter<u32>> = Left(1..3);
+    /// let mut fused = iter.fuse_either();
+    /// assert_eq!(fused.by_ref().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(fused.next(), None);
+    /// assert!(fused.is_left());
+    /// ```
+    pub fn fuse_either(self) -> Either<iter::Fuse<L>, iter::Fuse<R>>
+        where L: Iterator,
+              R: Iterator
+    {
+        match self {
+            Left(l) => Left(l.fuse()),
+            Right(r) => Right(r.fuse()),
+        }
+    }
+
+    /// Convert the inner value to an iterator, mapping the items of the left side with `f`
+    /// and the items of the right side with `g` to a common item type.
+    ///
+    /// Unlike `into_iter`, the two sides may have different item types. The result is an
+    /// `ExactSizeIterator` or `DoubleEndedIterator` if both inner iterators are.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<Vec<u32>, Vec<char>> = Left(vec![1, 2, 3]);
+    /// let iter = left.into_iter_map(|x| x.to_string(), |c| c.to_string());
+    /// assert_eq!(iter.collect::<Vec<_>>(), ["1", "2", "3"]);
+    ///
+    /// let right: Either<Vec<u32>, Vec<char>> = Right(vec!['a', 'b']);
+    /// let iter = right.into_iter_map(|x| x.to_string(), |c| c.to_string());
+    /// assert_eq!(iter.len(), 2);
+    /// assert_eq!(iter.collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    pub fn into_iter_map<F, G, T>(self, f: F, g: G)
+        -> Either<iter::Map<L::IntoIter, F>, iter::Map<R::IntoIter, G>>
+        where L: IntoIterator,
+              R: IntoIterator,
+              F: FnMut(L::Item) -> T,
+              G: FnMut(R::Item) -> T
+    {
+        match self {
+            Left(l) => Left(l.into_iter().map(f)),
+            Right(r) => Right(r.into_iter().map(g)),
+        }
+    }
+}
+
+impl<L> Either<L, iter::Empty<L::Item>>
+    where L: Iterator
+{
+    /// Wrap an iterator in `Left`, using `iter::Empty` as the type of the unused right side.
+    ///
+    /// This lets a single iterator be used where an `Either` of iterators is expected, without
+    /// having to name the right side's type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter = Either::left_iter_of(0..3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn left_iter_of(iter: L) -> Self {
+        Left(iter)
+    }
+}
+
+impl<R> Either<iter::Empty<R::Item>, R>
+    where R: Iterator
+{
+    /// Wrap an iterator in `Right`, using `iter::Empty` as the type of the unused left side.
+    ///
+    /// This lets a single iterator be used where an `Either` of iterators is expected, without
+    /// having to name the left side's type.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter = Either::right_iter_of(0..3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn right_iter_of(iter: R) -> Self {
+        Right(iter)
+    }
+}
+
+impl<T, R> Either<iter::Once<T>, R> {
+    /// Wrap a single value in `Left` as an iterator yielding it once, with `iter::once`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// fn values(all: bool) -> Either<std::iter::Once<u32>, std::ops::Range<u32>> {
+    ///     if all { Right(0..3) } else { Either::left_once(7) }
+    /// }
+    ///
+    /// assert_eq!(values(false).collect::<Vec<_>>(), vec![7]);
+    /// assert_eq!(values(true).collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    pub fn left_once(value: T) -> Self {
+        Left(iter::once(value))
+    }
+}
+
+impl<L, T> Either<L, iter::Once<T>> {
+    /// Wrap a single value in `Right` as an iterator yielding it once, with `iter::once`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let iter = Either::<std::iter::Empty<u32>, _>::right_once(7);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![7]);
+    /// ```
+    pub fn right_once(value: T) -> Self {
+        Right(iter::once(value))
+    }
+}
+
+impl<A, E> Either<Result<A, E>, Result<A, E>> {
+    /// Extract the `Result` of an either over two results of the same type, discarding the
+    /// side.
+    ///
+    /// This is the same as `into_inner`, and unrelated to the conversion from `Either<L, R>`
+    /// to `Result<R, L>`, which maps the sides themselves to `Ok` and `Err`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<Result<u32, &str>, Result<u32, &str>> = Left(Ok(123));
+    /// assert_eq!(left.into_result(), Ok(123));
+    ///
+    /// let right: Either<Result<u32, &str>, Result<u32, &str>> = Right(Err("error"));
+    /// assert_eq!(right.into_result(), Err("error"));
+    /// ```
+    pub fn into_result(self) -> Result<A, E> {
+        self.into_inner()
+    }
+}
+
+impl<L, R> Either<Option<L>, Option<R>> {
+    /// Take the value out of the present side's `Option`, like `Option::take`.
+    ///
+    /// Return the current contents, and leave `None` in the present side: `self` becomes
+    /// `Left(None)` if it was a `Left`, and `Right(None)` if it was a `Right`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut value: Either<Option<u32>, Option<&str>> = Left(Some(123));
+    /// assert_eq!(value.take(), Left(Some(123)));
+    /// assert_eq!(value, Left(None));
+    ///
+    /// let mut value: Either<Option<u32>, Option<&str>> = Right(Some("some value"));
+    /// assert_eq!(value.take(), Right(Some("some value")));
+    /// assert_eq!(value, Right(None));
+    /// ```
+    pub fn take(&mut self) -> Either<Option<L>, Option<R>> {
+        match *self {
+            Left(ref mut l) => Left(l.take()),
+            Right(ref mut r) => Right(r.take()),
+        }
+    }
+}
+
+impl<L, R> Either<Option<L>, R> {
+    /// Move an optional left value out of the `Either`.
+    ///
+    /// Return `None` if the value is `Left(None)`, otherwise the value with the left `Option`
+    /// unwrapped.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<Option<u32>, &str> = Left(Some(123));
+    /// assert_eq!(left.left_flatten(), Some(Left(123)));
+    ///
+    /// let left: Either<Option<u32>, &str> = Left(None);
+    /// assert_eq!(left.left_flatten(), None);
+    ///
+    /// let right: Either<Option<u32>, &str> = Right("fallback");
+    /// assert_eq!(right.left_flatten(), Some(Right("fallback")));
+    /// ```
+    pub fn left_flatten(self) -> Option<Either<L, R>> {
+        match self {
+            Left(l) => l.map(Left),
+            Right(r) => Some(Right(r)),
+        }
+    }
+}
+
+impl<L, R> Either<L, Option<R>> {
+    /// Move an optional right value out of the `Either`.
+    ///
+    /// Return `None` if the value is `Right(None)`, otherwise the value with the right
+    /// `Option` unwrapped.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<&str, Option<u32>> = Right(Some(123));
+    /// assert_eq!(right.right_flatten(), Some(Right(123)));
+    ///
+    /// let right: Either<&str, Option<u32>> = Right(None);
+    /// assert_eq!(right.right_flatten(), None);
+    ///
+    /// let left: Either<&str, Option<u32>> = Left("fallback");
+    /// assert_eq!(left.right_flatten(), Some(Left("fallback")));
+    /// ```
+    pub fn right_flatten(self) -> Option<Either<L, R>> {
+        match self {
+            Left(l) => Some(Left(l)),
+            Right(r) => r.map(Right),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<'a, L, R> Either<Cow<'a, L>, Cow<'a, R>>
+    where L: ToOwned + ?Sized, R: ToOwned + ?Sized
+{
+    /// Convert an either of `Cow`s to an either of owned values, cloning the data if it is
+    /// borrowed.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let text = String::from("borrowed");
+    /// let left: Either<Cow<str>, Cow<[u8]>> = Left(Cow::Borrowed(&text));
+    /// let owned: Either<String, Vec<u8>> = left.into_owned();
+    /// assert_eq!(owned, Left(String::from("borrowed")));
+    /// ```
+    pub fn into_owned(self) -> Either<L::Owned, R::Owned> {
+        match self {
+            Left(l) => Left(l.into_owned()),
+            Right(r) => Right(r.into_owned()),
+        }
+    }
+}
+
+impl<L> Either<&L, L> {
+    /// Borrow the value of an either of a borrowed and an owned `L`, like a `Cow` without
+    /// the `ToOwned` bound.
+    ///
+    /// `Deref` does not cover this case: it requires both sides to implement `Deref` with
+    /// the same target, and the owned `L` side dereferences to `L::Target`, if anything.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let shared = vec![1, 2, 3];
+    /// let borrowed: Either<&Vec<u32>, Vec<u32>> = Left(&shared);
+    /// let owned: Either<&Vec<u32>, Vec<u32>> = Right(vec![4]);
+    /// assert_eq!(borrowed.as_cow().len(), 3);
+    /// assert_eq!(owned.as_cow(), &vec![4]);
+    /// ```
+    pub fn as_cow(&self) -> &L {
+        match *self {
+            Left(l) => l,
+            Right(ref r) => r,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl Either<Box<dyn Any>, Box<dyn Any>> {
+    /// Attempt to downcast the boxed value to a concrete type, keeping its side.
+    ///
+    /// Return the original value if it is not a `T`.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::any::Any;
+    ///
+    /// let value: Either<Box<dyn Any>, Box<dyn Any>> = Right(Box::new(123u32));
+    /// let value = value.downcast::<String>().unwrap_err();
+    /// assert_eq!(value.downcast::<u32>().unwrap(), Right(Box::new(123)));
+    /// ```
+    pub fn downcast<T: Any>(self) -> Result<Either<Box<T>, Box<T>>, Self> {
+        match self {
+            Left(l) => l.downcast().map(Left).map_err(Left),
+            Right(r) => r.downcast().map(Right).map_err(Right),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+impl<L: Any, R: Any> Either<L, R> {
+    /// Return true if the value, on whichever side it is, has the type `T`.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: Either<u32, String> = Right(String::from("text"));
+    /// assert!(value.is::<String>());
+    /// assert!(!value.is::<u32>());
+    /// ```
+    pub fn is<T: Any>(&self) -> bool {
+        either!(*self, ref inner => (inner as &dyn Any).is::<T>())
+    }
+
+    /// Return a reference to the value, on whichever side it is, if it has the type `T`.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let value: Either<u32, String> = Left(123);
+    /// assert_eq!(value.downcast_ref::<u32>(), Some(&123));
+    /// assert_eq!(value.downcast_ref::<String>(), None);
+    /// ```
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        either!(*self, ref inner => (inner as &dyn Any).downcast_ref::<T>())
+    }
+}
+
+impl<T, L, R> Either<(T, L), (T, R)> {
+    /// Factor out a homogeneous type from an either of pairs.
+    ///
+    /// Here, the homogeneous type is the first element of the pairs.
+    ///
+    /// ```
+    /// use either::*;
+    /// let left: Either<_, (u32, String)> = Left((123, vec![0]));
+    /// assert_eq!(left.factor_first().0, 123);
     ///
     /// let right: Either<(u32, Vec<u8>), _> = Right((123, String::new()));
     /// assert_eq!(right.factor_first().0, 123);
@@ -435,6 +2673,49 @@ impl<T, L, R> Either<(L, T), (R, T)> {
     }
 }
 
+impl<A, B> Either<(A, B), (A, B)> {
+    /// Split an either of pairs into a pair of eithers, both on the original side.
+    ///
+    /// Unlike `factor_first` and `factor_second`, both components stay wrapped, so the side
+    /// they came from is not lost.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<(u32, char), (u32, char)> = Left((1, 'a'));
+    /// assert_eq!(left.unzip(), (Left(1), Left('a')));
+    ///
+    /// let right: Either<(u32, char), (u32, char)> = Right((2, 'b'));
+    /// assert_eq!(right.unzip(), (Right(2), Right('b')));
+    /// ```
+    pub fn unzip(self) -> (Either<A, A>, Either<B, B>) {
+        match self {
+            Left((a, b)) => (Left(a), Left(b)),
+            Right((a, b)) => (Right(a), Right(b)),
+        }
+    }
+
+    /// Apply the function `f` to the components of the pair, keeping the side.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<(u32, u32), (u32, u32)> = Left((1, 2));
+    /// assert_eq!(left.reduce_pair(|a, b| a + b), Left(3));
+    ///
+    /// let right: Either<(u32, u32), (u32, u32)> = Right((3, 4));
+    /// assert_eq!(right.reduce_pair(|a, b| a * b), Right(12));
+    /// ```
+    pub fn reduce_pair<F, C>(self, f: F) -> Either<C, C>
+        where F: FnOnce(A, B) -> C
+    {
+        match self {
+            Left((a, b)) => Left(f(a, b)),
+            Right((a, b)) => Right(f(a, b)),
+        }
+    }
+}
+
 impl<T> Either<T, T> {
     /// Extract the value of an either over two equivalent types.
     ///
@@ -450,6 +2731,210 @@ impl<T> Either<T, T> {
     pub fn into_inner(self) -> T {
         either!(self, inner => inner)
     }
+
+    /// Extract the value of an either over two equivalent types, together with its side.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.either_tag(), (Side::Left, 123));
+    ///
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_eq!(right.either_tag(), (Side::Right, 123));
+    /// ```
+    pub fn either_tag(self) -> (Side, T) {
+        (self.side(), self.into_inner())
+    }
+
+    /// Move the value to the other side if `cond` is true, otherwise keep it as it is.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.flip_if(true), Right(123));
+    /// assert_eq!(left.flip_if(false), Left(123));
+    /// ```
+    pub fn flip_if(self, cond: bool) -> Either<T, T> {
+        if cond {
+            self.flip()
+        } else {
+            self
+        }
+    }
+
+    /// Move the value to the `Left` side, whatever side it was on.
+    ///
+    /// The derived `PartialEq` and `Hash` take the side into account, so this gives a
+    /// canonical value to compare or hash when the side does not matter.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// let right: Either<u32, _> = Right(123);
+    /// assert_ne!(left, right);
+    /// assert_eq!(left.normalize_left(), right.normalize_left());
+    /// assert_eq!(right.normalize_left(), Left(123));
+    /// ```
+    pub fn normalize_left(self) -> Either<T, T> {
+        Left(self.into_inner())
+    }
+
+    /// Move the value to the `Right` side, whatever side it was on.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let left: Either<_, u32> = Left(123);
+    /// assert_eq!(left.normalize_right(), Right(123));
+    /// ```
+    pub fn normalize_right(self) -> Either<T, T> {
+        Right(self.into_inner())
+    }
+
+    /// Return the value if it is on the side `side`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is on the other side.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let right: Either<u32, u32> = Right(123);
+    /// assert_eq!(right.unwrap_side(Side::Right), 123);
+    /// ```
+    #[track_caller]
+    pub fn unwrap_side(self, side: Side) -> T {
+        match (self, side) {
+            (Left(t), Side::Left) | (Right(t), Side::Right) => t,
+            (Left(_), side) => panic!("called `Either::unwrap_side({:?})` on a `Left` value", side),
+            (Right(_), side) => panic!("called `Either::unwrap_side({:?})` on a `Right` value", side),
+        }
+    }
+
+    /// Apply the function `f` on the value, whatever side it is on, and move the result to
+    /// the other side.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let pending: Either<u32, u32> = Left(123);
+    /// assert_eq!(pending.map_flip(|x| x * 2), Right(246));
+    ///
+    /// let done: Either<u32, u32> = Right(123);
+    /// assert_eq!(done.map_flip(|x| x.to_string()), Left(String::from("123")));
+    /// ```
+    pub fn map_flip<F, U>(self, f: F) -> Either<U, U>
+        where F: FnOnce(T) -> U
+    {
+        match self {
+            Left(l) => Right(f(l)),
+            Right(r) => Left(f(r)),
+        }
+    }
+
+    /// Swap the variants and values of two eithers, like `std::mem::swap`.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut a = Left(1);
+    /// let mut b = Right(2);
+    /// a.swap(&mut b);
+    /// assert_eq!(a, Right(2));
+    /// assert_eq!(b, Left(1));
+    /// ```
+    pub fn swap(&mut self, other: &mut Either<T, T>) {
+        mem::swap(self, other)
+    }
+
+    /// Swap only the values of two eithers, each one keeping its own variant.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let mut a = Left(1);
+    /// let mut b = Right(2);
+    /// a.swap_values(&mut b);
+    /// assert_eq!(a, Left(2));
+    /// assert_eq!(b, Right(1));
+    /// ```
+    pub fn swap_values(&mut self, other: &mut Either<T, T>) {
+        mem::swap(self.as_mut().into_inner(), other.as_mut().into_inner())
+    }
+
+    /// Update the value in place with the function `f`, which receives the current side and
+    /// value and returns the new side and value.
+    ///
+    /// The value is moved out of `self` while `f` runs, so if `f` panics the process is
+    /// aborted rather than leaving `self` without a value or on another side.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// // move the counter to the right once it reaches 3
+    /// let mut state: Either<u32, u32> = Left(2);
+    /// let step = |side, n| if n + 1 >= 3 { (Side::Right, n + 1) } else { (side, n + 1) };
+    /// state.update(step);
+    /// assert_eq!(state, Right(3));
+    /// state.update(step);
+    /// assert_eq!(state, Right(4));
+    /// ```
+    pub fn update<F>(&mut self, f: F)
+        where F: FnOnce(Side, T) -> (Side, T)
+    {
+        // Panicking again while `f` unwinds aborts the process.
+        struct AbortOnUnwind;
+
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                panic!("`Either::update` callback panicked");
+            }
+        }
+
+        let side = self.side();
+        // Safety: `self` is not read again until it is overwritten below, and if `f`
+        // panics the guard aborts before `self` can be observed or dropped.
+        let value = unsafe { ptr::read(self) }.into_inner();
+        let guard = AbortOnUnwind;
+        let updated = match f(side, value) {
+            (Side::Left, value) => Left(value),
+            (Side::Right, value) => Right(value),
+        };
+        mem::forget(guard);
+        unsafe { ptr::write(self, updated) };
+    }
+}
+
+/// Extension trait for a `Result` holding an `Either` in both variants.
+///
+/// `Result` is a foreign type, so its `into_either` method is provided by this trait.
+pub trait EitherResultExt<L, R> {
+    /// Extract the `Either`, discarding the `Ok`/`Err` distinction.
+    ///
+    /// Only use this when it does not matter whether the value came from `Ok` or `Err`.
+    /// This is the method form of
+    /// [`Either::flatten_result`](enum.Either.html#method.flatten_result).
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// let ok: Result<Either<u32, char>, Either<u32, char>> = Ok(Left(1));
+    /// assert_eq!(ok.into_either(), Left(1));
+    ///
+    /// let err: Result<Either<u32, char>, Either<u32, char>> = Err(Right('a'));
+    /// assert_eq!(err.into_either(), Right('a'));
+    /// ```
+    fn into_either(self) -> Either<L, R>;
+}
+
+impl<L, R> EitherResultExt<L, R> for Result<Either<L, R>, Either<L, R>> {
+    fn into_either(self) -> Either<L, R> {
+        Either::flatten_result(self)
+    }
 }
 
 /// Convert from `Result` to `Either` with `Ok => Right` and `Err => Left`.
@@ -463,6 +2948,7 @@ impl<L, R> From<Result<R, L>> for Either<L, R> {
 }
 
 /// Convert from `Either` to `Result` with `Right => Ok` and `Left => Err`.
+#[allow(clippy::from_over_into)]
 impl<L, R> Into<Result<R, L>> for Either<L, R> {
     fn into(self) -> Result<R, L> {
         match self {
@@ -520,6 +3006,12 @@ impl<L, R> Iterator for Either<L, R>
         either!(self, inner => inner.collect())
     }
 
+    fn sum<S>(self) -> S
+        where S: iter::Sum<Self::Item>
+    {
+        either!(self, inner => inner.sum())
+    }
+
     fn all<F>(&mut self, f: F) -> bool
         where F: FnMut(Self::Item) -> bool
     {
@@ -535,6 +3027,15 @@ impl<L, R> DoubleEndedIterator for Either<L, R>
     }
 }
 
+/// `Either<L, R>` is an `ExactSizeIterator` only if *both* `L` and `R` are, so it is not
+/// one when a side may be unbounded:
+///
+/// ```compile_fail
+/// use either::*;
+///
+/// let iter: Either<std::ops::Range<u32>, std::ops::RangeFrom<u32>> = Right(0..);
+/// iter.len();
+/// ```
 impl<L, R> ExactSizeIterator for Either<L, R>
     where L: ExactSizeIterator, R: ExactSizeIterator<Item=L::Item>
 {
@@ -571,18 +3072,50 @@ impl<L, R> BufRead for Either<L, R>
 }
 
 #[cfg(any(test, feature = "use_std"))]
-/// `Either<L, R>` implements `Write` if both `L` and `R` do.
+/// `Either<L, R>` implements `Write` if both `L` and `R` do.
+///
+/// Requires crate feature `"use_std"`
+impl<L, R> Write for Either<L, R>
+    where L: Write, R: Write
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        either!(*self, ref mut inner => inner.write(buf))
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        either!(*self, ref mut inner => inner.write_all(buf))
+    }
+
+    fn write_fmt(&mut self, fmt: fmt::Arguments) -> io::Result<()> {
+        either!(*self, ref mut inner => inner.write_fmt(fmt))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        either!(*self, ref mut inner => inner.flush())
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+/// `Either<L, R>` implements `ToSocketAddrs` if both `L` and `R` do, with the same
+/// `Iter` type.
 ///
 /// Requires crate feature `"use_std"`
-impl<L, R> Write for Either<L, R>
-    where L: Write, R: Write
+///
+/// ```
+/// use either::*;
+/// use std::net::{SocketAddr, ToSocketAddrs};
+///
+/// let addr: Either<(&str, u16), (String, u16)> = Right((String::from("127.0.0.1"), 80));
+/// let resolved: Vec<SocketAddr> = addr.to_socket_addrs().unwrap().collect();
+/// assert_eq!(resolved, vec!["127.0.0.1:80".parse().unwrap()]);
+/// ```
+impl<L, R> ToSocketAddrs for Either<L, R>
+    where L: ToSocketAddrs, R: ToSocketAddrs<Iter = L::Iter>
 {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        either!(*self, ref mut inner => inner.write(buf))
-    }
+    type Iter = L::Iter;
 
-    fn flush(&mut self) -> io::Result<()> {
-        either!(*self, ref mut inner => inner.flush())
+    fn to_socket_addrs(&self) -> io::Result<Self::Iter> {
+        either!(*self, ref inner => inner.to_socket_addrs())
     }
 }
 
@@ -607,6 +3140,7 @@ impl<L, R> Deref for Either<L, R>
 {
     type Target = L::Target;
 
+    #[allow(clippy::borrow_deref_ref)]
     fn deref(&self) -> &Self::Target {
         either!(*self, ref inner => &*inner)
     }
@@ -620,6 +3154,95 @@ impl<L, R> DerefMut for Either<L, R>
     }
 }
 
+/// `Either<L, R>` implements `Index` if both `L` and `R` do, with the same `Output` type.
+///
+/// ```
+/// use either::*;
+/// use std::collections::VecDeque;
+///
+/// let mut values: Either<Vec<u32>, VecDeque<u32>> = Right(VecDeque::from(vec![1, 2, 3]));
+/// assert_eq!(values[1], 2);
+/// values[1] = 20;
+/// assert_eq!(values[1], 20);
+/// ```
+impl<L, R, Idx> Index<Idx> for Either<L, R>
+    where L: Index<Idx>, R: Index<Idx, Output = L::Output>
+{
+    type Output = L::Output;
+
+    fn index(&self, index: Idx) -> &Self::Output {
+        either!(*self, ref inner => &inner[index])
+    }
+}
+
+/// `Either<L, R>` implements `IndexMut` if both `L` and `R` do, with the same `Output` type.
+impl<L, R, Idx> IndexMut<Idx> for Either<L, R>
+    where L: IndexMut<Idx>, R: IndexMut<Idx, Output = L::Output>
+{
+    fn index_mut(&mut self, index: Idx) -> &mut Self::Output {
+        either!(*self, ref mut inner => &mut inner[index])
+    }
+}
+
+/// `Either<T, T>` implements `Add` if `T` does, adding the inner values whatever their
+/// sides.
+///
+/// The result is a bare `T`: the sides of the operands are discarded. `Sub`, `Mul` and
+/// `Div` are implemented the same way.
+///
+/// ```
+/// use either::*;
+///
+/// let left: Either<u32, u32> = Left(5);
+/// let right: Either<u32, u32> = Right(3);
+/// assert_eq!(left + right, 8);
+/// assert_eq!(left - right, 2);
+/// assert_eq!(left * right, 15);
+/// assert_eq!(left / right, 1);
+/// ```
+impl<T> Add for Either<T, T>
+    where T: Add<Output = T>
+{
+    type Output = T;
+
+    fn add(self, rhs: Self) -> T {
+        self.into_inner() + rhs.into_inner()
+    }
+}
+
+/// `Either<T, T>` implements `Sub` if `T` does, discarding the sides of the operands.
+impl<T> Sub for Either<T, T>
+    where T: Sub<Output = T>
+{
+    type Output = T;
+
+    fn sub(self, rhs: Self) -> T {
+        self.into_inner() - rhs.into_inner()
+    }
+}
+
+/// `Either<T, T>` implements `Mul` if `T` does, discarding the sides of the operands.
+impl<T> Mul for Either<T, T>
+    where T: Mul<Output = T>
+{
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> T {
+        self.into_inner() * rhs.into_inner()
+    }
+}
+
+/// `Either<T, T>` implements `Div` if `T` does, discarding the sides of the operands.
+impl<T> Div for Either<T, T>
+    where T: Div<Output = T>
+{
+    type Output = T;
+
+    fn div(self, rhs: Self) -> T {
+        self.into_inner() / rhs.into_inner()
+    }
+}
+
 #[cfg(all(feature = "use_std", feature = "try_trait"))]
 /// Requires crate feature `"use_std"`
 impl<L, R> Try for Either<L, R> {
@@ -647,15 +3270,47 @@ impl<L, R> Try for Either<L, R> {
 impl<L, R> Error for Either<L, R>
     where L: Error, R: Error
 {
+    #[allow(deprecated)]
     fn description(&self) -> &str {
         either!(*self, ref inner => inner.description())
     }
 
+    #[allow(deprecated, bare_trait_objects)]
     fn cause(&self) -> Option<&Error> {
         either!(*self, ref inner => inner.cause())
     }
 }
 
+#[cfg(any(test, feature = "use_std"))]
+impl<L, R> Either<L, R>
+    where L: Error + 'static, R: Error + 'static
+{
+    /// Borrow the inner value as an error trait object, without treating
+    /// the `Either` itself as the error.
+    ///
+    /// Requires crate feature `"use_std"`
+    ///
+    /// ```
+    /// use either::*;
+    /// use std::error::Error;
+    /// use std::num::ParseIntError;
+    /// use std::fmt;
+    ///
+    /// fn report(err: &dyn Error) -> String {
+    ///     err.to_string()
+    /// }
+    ///
+    /// let err: Either<ParseIntError, fmt::Error> = Left("x".parse::<i32>().unwrap_err());
+    /// assert_eq!(report(err.as_dyn_error()), "invalid digit found in string");
+    ///
+    /// let err: Either<ParseIntError, fmt::Error> = Right(fmt::Error);
+    /// assert_eq!(report(err.as_dyn_error()), fmt::Error.to_string());
+    /// ```
+    pub fn as_dyn_error(&self) -> &(dyn Error + 'static) {
+        either!(*self, ref inner => inner)
+    }
+}
+
 impl<L, R> fmt::Display for Either<L, R>
     where L: fmt::Display, R: fmt::Display
 {
@@ -664,6 +3319,273 @@ impl<L, R> fmt::Display for Either<L, R>
     }
 }
 
+/// Parse a string as `L` if possible, otherwise as `R`.
+///
+/// ```
+/// use either::*;
+///
+/// let number: Either<u32, bool> = "42".parse().unwrap();
+/// assert_eq!(number, Left(42));
+///
+/// let keyword: Either<u32, bool> = "true".parse().unwrap();
+/// assert_eq!(keyword, Right(true));
+///
+/// assert!("neither".parse::<Either<u32, bool>>().is_err());
+/// ```
+impl<L, R> FromStr for Either<L, R>
+    where L: FromStr, R: FromStr
+{
+    type Err = ParseEitherError<L::Err, R::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match L::from_str(s) {
+            Ok(l) => Ok(Left(l)),
+            Err(left) => match R::from_str(s) {
+                Ok(r) => Ok(Right(r)),
+                Err(right) => Err(ParseEitherError { left, right }),
+            },
+        }
+    }
+}
+
+/// The error returned when parsing an `Either` fails for both sides.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseEitherError<LE, RE> {
+    /// The error from parsing the left side.
+    pub left: LE,
+    /// The error from parsing the right side.
+    pub right: RE,
+}
+
+impl<LE, RE> fmt::Display for ParseEitherError<LE, RE>
+    where LE: fmt::Display, RE: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to parse either side: {}; {}", self.left, self.right)
+    }
+}
+
+#[cfg(any(test, feature = "use_std"))]
+/// Requires crate feature `"use_std"`
+impl<LE, RE> Error for ParseEitherError<LE, RE>
+    where LE: Error, RE: Error
+{
+}
+
+/// A `Display` adapter that shows the `Left` value of an `Either`, and nothing or a
+/// placeholder for a `Right`.
+///
+/// Created with [`Either::left_display`](enum.Either.html#method.left_display).
+#[derive(Debug)]
+pub struct LeftDisplay<'a, L: 'a, R: 'a> {
+    inner: &'a Either<L, R>,
+    placeholder: &'a str,
+}
+
+impl<'a, L, R> LeftDisplay<'a, L, R> {
+    /// Display `placeholder` instead of nothing for a `Right` value.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// struct Opaque;
+    ///
+    /// let right: Either<u32, Opaque> = Right(Opaque);
+    /// assert_eq!(right.left_display().placeholder("<none>").to_string(), "<none>");
+    /// ```
+    pub fn placeholder(self, placeholder: &'a str) -> Self {
+        LeftDisplay { placeholder, ..self }
+    }
+}
+
+impl<'a, L, R> fmt::Display for LeftDisplay<'a, L, R>
+    where L: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.inner {
+            Left(ref l) => l.fmt(f),
+            Right(_) => f.write_str(self.placeholder),
+        }
+    }
+}
+
+/// A `Display` adapter that shows the `Right` value of an `Either`, and nothing or a
+/// placeholder for a `Left`.
+///
+/// Created with [`Either::right_display`](enum.Either.html#method.right_display).
+#[derive(Debug)]
+pub struct RightDisplay<'a, L: 'a, R: 'a> {
+    inner: &'a Either<L, R>,
+    placeholder: &'a str,
+}
+
+impl<'a, L, R> RightDisplay<'a, L, R> {
+    /// Display `placeholder` instead of nothing for a `Left` value.
+    ///
+    /// ```
+    /// use either::*;
+    ///
+    /// struct Opaque;
+    ///
+    /// let left: Either<Opaque, u32> = Left(Opaque);
+    /// assert_eq!(left.right_display().placeholder("<none>").to_string(), "<none>");
+    /// ```
+    pub fn placeholder(self, placeholder: &'a str) -> Self {
+        RightDisplay { placeholder, ..self }
+    }
+}
+
+impl<'a, L, R> fmt::Display for RightDisplay<'a, L, R>
+    where R: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.inner {
+            Left(_) => f.write_str(self.placeholder),
+            Right(ref r) => r.fmt(f),
+        }
+    }
+}
+
+/// A formatting adapter that shows the value of an `Either` prefixed with `L:` or `R:`.
+///
+/// Created with [`Either::debug_tagged`](enum.Either.html#method.debug_tagged).
+pub struct DebugTagged<'a, L: 'a, R: 'a> {
+    inner: &'a Either<L, R>,
+}
+
+impl<'a, L, R> fmt::Debug for DebugTagged<'a, L, R>
+    where L: fmt::Debug, R: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.inner {
+            Left(ref l) => {
+                f.write_str("L:")?;
+                fmt::Debug::fmt(l, f)
+            }
+            Right(ref r) => {
+                f.write_str("R:")?;
+                fmt::Debug::fmt(r, f)
+            }
+        }
+    }
+}
+
+impl<'a, L, R> fmt::Display for DebugTagged<'a, L, R>
+    where L: fmt::Display, R: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self.inner {
+            Left(ref l) => {
+                f.write_str("L:")?;
+                fmt::Display::fmt(l, f)
+            }
+            Right(ref r) => {
+                f.write_str("R:")?;
+                fmt::Display::fmt(r, f)
+            }
+        }
+    }
+}
+
+/// A future that maps the `Left` value of an `Either` with an asynchronous function.
+///
+/// Created with [`Either::map_left_fut`](enum.Either.html#method.map_left_fut).
+///
+/// Requires crate feature `"future"`
+#[cfg(feature = "future")]
+#[must_use = "futures do nothing unless polled"]
+pub struct MapLeftFuture<Fut, R> {
+    inner: Either<Fut, Option<R>>,
+}
+
+#[cfg(feature = "future")]
+impl<Fut, R> Future for MapLeftFuture<Fut, R>
+    where Fut: Future
+{
+    type Output = Either<Fut::Output, R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: only the future is pinned structurally; it is never moved out of
+        // `inner`, while the passed-through value is never pinned and can be taken.
+        match unsafe { self.get_unchecked_mut() }.inner {
+            Left(ref mut fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx).map(Left),
+            Right(ref mut r) => {
+                Poll::Ready(Right(r.take().expect("MapLeftFuture polled after completion")))
+            }
+        }
+    }
+}
+
+/// A future that maps the `Right` value of an `Either` with an asynchronous function.
+///
+/// Created with [`Either::map_right_fut`](enum.Either.html#method.map_right_fut).
+///
+/// Requires crate feature `"future"`
+#[cfg(feature = "future")]
+#[must_use = "futures do nothing unless polled"]
+pub struct MapRightFuture<L, Fut> {
+    inner: Either<Option<L>, Fut>,
+}
+
+#[cfg(feature = "future")]
+impl<L, Fut> Future for MapRightFuture<L, Fut>
+    where Fut: Future
+{
+    type Output = Either<L, Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: only the future is pinned structurally; it is never moved out of
+        // `inner`, while the passed-through value is never pinned and can be taken.
+        match unsafe { self.get_unchecked_mut() }.inner {
+            Left(ref mut l) => {
+                Poll::Ready(Left(l.take().expect("MapRightFuture polled after completion")))
+            }
+            Right(ref mut fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx).map(Right),
+        }
+    }
+}
+
+/// Project a pinned `Either` to its pinned value.
+#[cfg(feature = "future")]
+fn project_pin<L, R>(this: Pin<&mut Either<L, R>>) -> Either<Pin<&mut L>, Pin<&mut R>> {
+    // Safety: the value is pinned structurally, since it is never moved out from behind a
+    // pinned reference and `Either` has no `Drop` impl.
+    unsafe {
+        match *Pin::get_unchecked_mut(this) {
+            Left(ref mut inner) => Left(Pin::new_unchecked(inner)),
+            Right(ref mut inner) => Right(Pin::new_unchecked(inner)),
+        }
+    }
+}
+
+/// A future that awaits the future in an `Either`, keeping its side.
+///
+/// Created with [`Either::await_either`](enum.Either.html#method.await_either).
+///
+/// Requires crate feature `"future"`
+#[cfg(feature = "future")]
+#[must_use = "futures do nothing unless polled"]
+pub struct AwaitEither<L, R> {
+    inner: Either<L, R>,
+}
+
+#[cfg(feature = "future")]
+impl<L, R> Future for AwaitEither<L, R>
+    where L: Future,
+          R: Future
+{
+    type Output = Either<L::Output, R::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: `inner` is pinned structurally and never moved out of.
+        let inner = unsafe { self.map_unchecked_mut(|this| &mut this.inner) };
+        match project_pin(inner) {
+            Left(fut) => fut.poll(cx).map(Left),
+            Right(fut) => fut.poll(cx).map(Right),
+        }
+    }
+}
+
 #[test]
 fn basic() {
     let mut e = Left(2);
@@ -691,6 +3613,23 @@ fn macros() {
     assert_eq!(b(), Left(String::from("foo bar")));
 }
 
+#[test]
+fn either_ref_as_dyn() {
+    trait Named {
+        fn name(&self) -> String;
+    }
+    impl Named for u8 {
+        fn name(&self) -> String { format!("u8 {}", self) }
+    }
+    impl Named for char {
+        fn name(&self) -> String { format!("char {}", self) }
+    }
+    let values: [Either<u8, char>; 2] = [Left(7), Right('x')];
+    let names: Vec<_> = values.iter().map(|v| either_ref_as_dyn!(v => Named).name()).collect();
+    assert_eq!(names, ["u8 7", "char x"]);
+}
+
+#[allow(clippy::explicit_auto_deref)]
 #[test]
 fn deref() {
     fn is_str(_: &str) {}
@@ -698,6 +3637,7 @@ fn deref() {
     is_str(&*value);
 }
 
+#[allow(ellipsis_inclusive_range_patterns)]
 #[test]
 fn iter() {
     let x = 3;
@@ -710,6 +3650,17 @@ fn iter() {
     assert_eq!(iter.count(), 9);
 }
 
+#[test]
+fn size_hint_unbounded() {
+    let bounded: Either<_, ::std::ops::RangeFrom<u32>> = Left(0..10);
+    assert_eq!(bounded.size_hint(), (0..10).size_hint());
+    assert_eq!(bounded.size_hint(), (10, Some(10)));
+
+    let unbounded: Either<::std::ops::Range<u32>, _> = Right(17..);
+    assert_eq!(unbounded.size_hint(), (17..).size_hint());
+    assert_eq!(unbounded.size_hint(), (usize::MAX, None));
+}
+
 #[test]
 fn read_write() {
     use std::io;
@@ -738,6 +3689,254 @@ fn read_write() {
     assert_eq!(writer.write(&buf).unwrap(), buf.len());
 }
 
+#[test]
+fn write_all_forwarding() {
+    use std::io;
+
+    /// A writer that only supports `write_all`, to check that it is forwarded.
+    struct WriteAllOnly(Vec<u8>);
+
+    impl Write for WriteAllOnly {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            panic!("write_all was not forwarded");
+        }
+
+        fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+            self.0.extend_from_slice(buf);
+            Ok(())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer: Either<WriteAllOnly, io::Sink> = Left(WriteAllOnly(Vec::new()));
+    writer.write_all(b"hello ").unwrap();
+    write!(writer, "{}!", "world".len()).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.left().unwrap().0, b"hello 5!");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "called `Either::debug_assert_left()` on a `Right` value")]
+fn debug_assert_left_panics() {
+    let right: Either<(), u32> = Right(123);
+    right.debug_assert_left();
+}
+
+#[test]
+#[should_panic(expected = "must be left: 5")]
+fn expect_left_same_types() {
+    Either::<i32, i32>::Right(5).expect_left("must be left");
+}
+
+#[test]
+fn unwrap_side() {
+    assert_eq!(Either::<i32, i32>::Left(5).unwrap_side(Side::Left), 5);
+    assert_eq!(Either::<i32, i32>::Right(6).unwrap_side(Side::Right), 6);
+}
+
+#[test]
+#[should_panic(expected = "called `Either::unwrap_side(Left)` on a `Right` value")]
+fn unwrap_side_mismatch() {
+    Either::<i32, i32>::Right(5).unwrap_side(Side::Left);
+}
+
+#[test]
+fn update_keeps_side() {
+    struct Token(u32);
+
+    let mut state: Either<Token, Token> = Right(Token(1));
+    state.update(|side, Token(n)| (side, Token(n + 1)));
+    match state {
+        Right(Token(2)) => {}
+        _ => panic!("expected Right(Token(2))"),
+    }
+
+    state.update(|_, token| (Side::Left, token));
+    state.update(|side, Token(n)| (side, Token(n * 10)));
+    match state {
+        Left(Token(20)) => {}
+        _ => panic!("expected Left(Token(20))"),
+    }
+}
+
+#[test]
+fn expect_same_types() {
+    assert_eq!(Either::<i32, i32>::Left(5).expect_left("must be left"), 5);
+    assert_eq!(Either::<i32, i32>::Right(5).expect_right("must be right"), 5);
+}
+
+#[test]
+fn try_into_inner() {
+    let left: Either<i64, u64> = Left(7);
+    assert_eq!(left.try_into_inner::<u8>(), Ok(7));
+    let right: Either<i64, u64> = Right(7);
+    assert_eq!(right.try_into_inner::<u8>(), Ok(7));
+
+    let left: Either<i64, u64> = Left(-1);
+    assert!(left.try_into_inner::<u8>().unwrap_err().is_left());
+    let right: Either<i64, u64> = Right(256);
+    assert!(right.try_into_inner::<u8>().unwrap_err().is_right());
+}
+
+#[test]
+fn coerce() {
+    let left: Either<i64, u64> = Left(7);
+    assert_eq!(left.coerce::<u8>(), Ok(7));
+    let right: Either<i64, u64> = Right(7);
+    assert_eq!(right.coerce::<u8>(), Ok(7));
+
+    let left: Either<i64, u64> = Left(-1);
+    assert!(left.coerce::<u8>().unwrap_err().is_left());
+    let right: Either<i64, u64> = Right(256);
+    assert!(right.coerce::<u8>().unwrap_err().is_right());
+}
+
+#[test]
+fn as_ref_generic() {
+    trait Named {
+        fn name(&self) -> &str;
+    }
+    impl Named for String {
+        fn name(&self) -> &str { self }
+    }
+
+    struct Holder<L, R> {
+        value: Either<L, R>,
+    }
+
+    fn left_name<'a, L, R>(holder: &'a Holder<L, R>) -> Option<&'a str>
+        where L: Named
+    {
+        let borrowed: Either<&'a L, &'a R> = holder.value.as_ref();
+        borrowed.left().map(Named::name)
+    }
+
+    fn first_left<'a, L, R, I>(values: I) -> Option<&'a L>
+        where I: IntoIterator<Item = &'a Either<L, R>>,
+              L: 'a + Named,
+              R: 'a
+    {
+        values.into_iter().filter_map(|v| v.as_ref().left()).next()
+    }
+
+    fn left_name_lt<'a, L, R>(holder: &'a Holder<L, R>) -> Option<&'a str>
+        where L: Named
+    {
+        let borrowed: Either<&'a L, &'a R> = holder.value.as_ref_lt();
+        borrowed.left().map(Named::name)
+    }
+
+    let holder: Holder<String, u32> = Holder { value: Left(String::from("name")) };
+    assert_eq!(left_name(&holder), Some("name"));
+    assert_eq!(left_name_lt(&holder), Some("name"));
+    let holder: Holder<String, u32> = Holder { value: Right(1) };
+    assert_eq!(left_name(&holder), None);
+    assert_eq!(left_name_lt(&holder), None);
+
+    let values: Vec<Either<String, u32>> = vec![Right(1), Left(String::from("first"))];
+    assert_eq!(first_left(&values).map(Named::name), Some("first"));
+}
+
+#[test]
+fn map_lefts_reuses_allocation() {
+    use iterator::{map_lefts, map_rights};
+
+    let mut values: Vec<Either<u32, u32>> = Vec::with_capacity(8);
+    values.extend(vec![Left(1), Right(2), Left(3)]);
+    let (ptr, cap) = (values.as_ptr() as usize, values.capacity());
+    let mapped = map_lefts(values, |x| -(x as i32));
+    assert_eq!(mapped, vec![Left(-1), Right(2), Left(-3)]);
+    assert_eq!((mapped.as_ptr() as usize, mapped.capacity()), (ptr, cap));
+
+    let mapped = map_rights(mapped, |x| x as i32);
+    assert_eq!(mapped, vec![Left(-1), Right(2), Left(-3)]);
+    assert_eq!((mapped.as_ptr() as usize, mapped.capacity()), (ptr, cap));
+
+    let widened = map_rights(mapped, |x| x as u64);
+    assert_eq!(widened, vec![Left(-1), Right(2), Left(-3)]);
+}
+
+#[test]
+fn map_lefts_panic_drops_each_value_once() {
+    use iterator::map_lefts;
+    use std::panic;
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let values: Vec<Either<Rc<()>, Rc<()>>> = (0..6)
+        .map(|i| if i % 2 == 0 { Left(counter.clone()) } else { Right(counter.clone()) })
+        .collect();
+    let mut calls = 0;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        map_lefts(values, |rc| {
+            calls += 1;
+            if calls == 2 {
+                panic!("stop");
+            }
+            rc
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn filter_map_debug() {
+    use iterator::IteratorExt;
+
+    let values: Vec<Either<u32, char>> = vec![Left(1)];
+    let iter = values.into_iter().filter_map_left(|x| Some(x + 1));
+    assert!(format!("{:?}", iter).starts_with("FilterMapLeft { iter: "));
+}
+
+#[test]
+fn partition_in_place() {
+    use iterator::partition_in_place_by_side;
+
+    let original: Vec<Either<u32, char>> =
+        vec![Right('a'), Left(1), Right('b'), Left(2), Left(3), Right('c'), Left(4)];
+    let mut values = original.clone();
+    let pivot = partition_in_place_by_side(&mut values);
+
+    assert_eq!(pivot, 4);
+    assert!(values[..pivot].iter().all(Either::is_left));
+    assert!(values[pivot..].iter().all(Either::is_right));
+    let mut sorted = values.clone();
+    sorted.sort();
+    let mut expected = original.clone();
+    expected.sort();
+    assert_eq!(sorted, expected);
+
+    let mut empty: [Either<u32, char>; 0] = [];
+    assert_eq!(partition_in_place_by_side(&mut empty), 0);
+    let mut rights = [Right::<u32, _>('x'), Right('y')];
+    assert_eq!(partition_in_place_by_side(&mut rights), 0);
+    let mut lefts = [Left::<_, char>(1), Left(2)];
+    assert_eq!(partition_in_place_by_side(&mut lefts), 2);
+}
+
+#[test]
+fn map_keys() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let keys: Vec<Either<u32, &str>> = vec![Right("b"), Left(2), Right("a"), Left(1)];
+
+    let hash: HashMap<_, _> = keys.iter().cloned().zip(0..).collect();
+    assert_eq!(hash[&Left(2)], 1);
+    assert_eq!(hash[&Right("a")], 2);
+    assert_eq!(hash.get(&Left(3)), None);
+
+    let btree: BTreeMap<_, _> = keys.iter().cloned().zip(0..).collect();
+    assert_eq!(btree.keys().cloned().collect::<Vec<_>>(),
+               vec![Left(1), Left(2), Right("a"), Right("b")]);
+    assert_eq!(btree[&Right("b")], 0);
+}
+
+#[allow(deprecated, unknown_lints, invalid_from_utf8)]
 #[test]
 fn error() {
     let invalid_utf8 = b"\xff";
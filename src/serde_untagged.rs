@@ -0,0 +1,81 @@
+//! Untagged serialization/deserialization support for `Either<L, R>`.
+//!
+//! `Either` uses default, externally-tagged representation.
+//! However, sometimes it is useful to support several alternative types.
+//! For example, we may have a field which is generally `Map<String, i32>`
+//! but in typical cases `Vec<String>` would suffice, too.
+//!
+//! Decoding tries `L` first and falls back to `R`, so the round trip is lossy
+//! whenever a serialized `R` also parses as `L`: an `Either<String, String>`
+//! always deserializes as `Left`.
+//!
+//! Requires crate feature `"serde"`
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate serde;
+//! extern crate serde_json;
+//! extern crate either;
+//!
+//! use either::Either;
+//! use std::collections::HashMap;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! #[serde(transparent)]
+//! struct StringsOrMap {
+//!     #[serde(with = "either::serde_untagged")]
+//!     inner: Either<Vec<String>, HashMap<String, i32>>
+//! }
+//!
+//! fn main() {
+//!     // serialization
+//!     let left = StringsOrMap {
+//!         inner: Either::Left(vec!["Hello".to_string()])
+//!     };
+//!     // notice: no tags are emitted.
+//!     let json = serde_json::to_string(&left).unwrap();
+//!     assert_eq!(json, r#"["Hello"]"#);
+//!     assert_eq!(serde_json::from_str::<StringsOrMap>(&json).unwrap(), left);
+//!
+//!     let mut map = HashMap::new();
+//!     map.insert("a".to_string(), 14);
+//!     let right = StringsOrMap { inner: Either::Right(map) };
+//!     let json = serde_json::to_string(&right).unwrap();
+//!     assert_eq!(json, r#"{"a":14}"#);
+//!     assert_eq!(serde_json::from_str::<StringsOrMap>(&json).unwrap(), right);
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Serialize an `Either` as its inner value, without a tag.
+pub fn serialize<L, R, S>(this: &::Either<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+          L: Serialize,
+          R: Serialize
+{
+    let untagged = match *this {
+        ::Either::Left(ref left) => Either::Left(left),
+        ::Either::Right(ref right) => Either::Right(right),
+    };
+    untagged.serialize(serializer)
+}
+
+/// Deserialize an `Either` from an untagged value, trying `L` first and then `R`.
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<::Either<L, R>, D::Error>
+    where D: Deserializer<'de>,
+          L: Deserialize<'de>,
+          R: Deserialize<'de>
+{
+    match Either::deserialize(deserializer)? {
+        Either::Left(left) => Ok(::Either::Left(left)),
+        Either::Right(right) => Ok(::Either::Right(right)),
+    }
+}
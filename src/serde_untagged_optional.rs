@@ -0,0 +1,82 @@
+//! Untagged serialization/deserialization support for `Option<Either<L, R>>`.
+//!
+//! This is the optional counterpart of [`serde_untagged`](../serde_untagged/index.html),
+//! with the same decoding order and caveats; `None` is serialized as `null`.
+//!
+//! Requires crate feature `"serde"`
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate serde;
+//! extern crate serde_json;
+//! extern crate either;
+//!
+//! use either::Either;
+//! use std::collections::HashMap;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! #[serde(transparent)]
+//! struct StringsOrMap {
+//!     #[serde(with = "either::serde_untagged_optional")]
+//!     inner: Option<Either<Vec<String>, HashMap<String, i32>>>
+//! }
+//!
+//! fn main() {
+//!     // serialization
+//!     let left = StringsOrMap {
+//!         inner: Some(Either::Left(vec!["Hello".to_string()]))
+//!     };
+//!     // notice: no tags are emitted.
+//!     let json = serde_json::to_string(&left).unwrap();
+//!     assert_eq!(json, r#"["Hello"]"#);
+//!     assert_eq!(serde_json::from_str::<StringsOrMap>(&json).unwrap(), left);
+//!
+//!     let mut map = HashMap::new();
+//!     map.insert("a".to_string(), 14);
+//!     let right = StringsOrMap { inner: Some(Either::Right(map)) };
+//!     let json = serde_json::to_string(&right).unwrap();
+//!     assert_eq!(json, r#"{"a":14}"#);
+//!     assert_eq!(serde_json::from_str::<StringsOrMap>(&json).unwrap(), right);
+//!
+//!     let none = StringsOrMap { inner: None };
+//!     let json = serde_json::to_string(&none).unwrap();
+//!     assert_eq!(json, "null");
+//!     assert_eq!(serde_json::from_str::<StringsOrMap>(&json).unwrap(), none);
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Serialize an optional `Either` as its inner value, without a tag.
+pub fn serialize<L, R, S>(this: &Option<::Either<L, R>>, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer,
+          L: Serialize,
+          R: Serialize
+{
+    let untagged = match *this {
+        Some(::Either::Left(ref left)) => Some(Either::Left(left)),
+        Some(::Either::Right(ref right)) => Some(Either::Right(right)),
+        None => None,
+    };
+    untagged.serialize(serializer)
+}
+
+/// Deserialize an optional `Either` from an untagged value, trying `L` first and then `R`.
+pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Option<::Either<L, R>>, D::Error>
+    where D: Deserializer<'de>,
+          L: Deserialize<'de>,
+          R: Deserialize<'de>
+{
+    match Option::deserialize(deserializer)? {
+        Some(Either::Left(left)) => Ok(Some(::Either::Left(left))),
+        Some(Either::Right(right)) => Ok(Some(::Either::Right(right))),
+        None => Ok(None),
+    }
+}